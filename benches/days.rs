@@ -0,0 +1,40 @@
+// Times the parse, part 1 and part 2 phases of every day on its real input,
+// e.g. `cargo bench -- day23` for a single day
+use std::{hint::black_box, path::Path};
+
+use advent_of_code::{
+    days::DAYS,
+    input::{asset, read_input},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    for (i, solution) in DAYS.iter().enumerate() {
+        let day = i + 1;
+        let file = asset(day, "in");
+        if !Path::new(&file).exists() {
+            continue;
+        }
+
+        let text = read_input(&file).unwrap();
+        let input = solution.parse(&text).unwrap();
+
+        let mut group = c.benchmark_group(format!("day{day}"));
+
+        group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(&text))));
+        group.bench_function("part1", |b| b.iter(|| solution.part1(black_box(&*input))));
+        if solution.has_part2() {
+            group.bench_function("part2", |b| b.iter(|| solution.part2(black_box(&*input))));
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // A few days take seconds per run, the default of 100 samples would take ages
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
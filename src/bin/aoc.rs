@@ -0,0 +1,401 @@
+use std::{
+    env,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    days::{
+        day10::Day10, day16::Day16, day17::Day17, day18::Day18, day20::Day20, day23::Day23, DAYS,
+    },
+    input::{asset, read_input, ParseError, Res},
+    solution::DynSolution,
+};
+
+const USAGE: &str =
+    "Usage: aoc run <day|all> [--part <1|2>] [--input <in|in_small|path|->] [--time]
+                   [--draw] [--trace] [--threads <n>] [--run-length <min>-<max>] [--svg <path>]
+                   [--dot <path>] [--mermaid <path>] [--presses <n>]";
+
+// Inputs checked in under `assets/dayN/` that can be picked by name
+const NAMED_INPUTS: &[&str] = &["in", "in_small"];
+
+// Days that can draw what they found with `--draw`
+const DRAWN_DAYS: &[usize] = &[10, 16, 17];
+
+struct Args {
+    days: Vec<usize>,
+    parts: Vec<usize>,
+    input: String,
+    time: bool,
+    draw: bool,
+    // Print the day 16 beams step by step
+    trace: bool,
+    // Threads for day 16 part 2, one per core by default
+    threads: Option<usize>,
+    // Crucible for day 17 instead of the ones of the puzzle
+    run_length: Option<(usize, usize)>,
+    // Where to draw the day 18 lagoon
+    svg: Option<String>,
+    // Where to write the day 20 module network or the day 23 junction graph
+    dot: Option<String>,
+    // Where to write the day 20 module network as a Mermaid flowchart
+    mermaid: Option<String>,
+    // Button presses before the day 20 drawings are colored
+    presses: Option<usize>,
+}
+
+// How long each phase of a day took, `None` when it did not run or failed
+struct Timing {
+    day: usize,
+    parse: Duration,
+    parts: [Option<Duration>; 2],
+}
+
+impl Timing {
+    fn total(&self) -> Duration {
+        self.parse + self.parts.iter().flatten().sum::<Duration>()
+    }
+}
+
+fn parse_args(args: &[String]) -> Res<Args> {
+    let mut args = args.iter();
+
+    match args.next().map(|x| x.as_str()) {
+        Some("run") => {}
+        Some(command) => return Err(format!("Unknown command `{command}`").into()),
+        None => return Err("Missing command".into()),
+    }
+
+    let days = match args.next().map(|x| x.as_str()) {
+        Some("all") => (1..=DAYS.len()).collect(),
+        Some(day) => {
+            let day = day
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid day `{day}`"))?;
+            if !(1..=DAYS.len()).contains(&day) {
+                return Err(format!("There is no day {day}").into());
+            }
+            vec![day]
+        }
+        None => return Err("Missing day".into()),
+    };
+
+    let mut parts = vec![1, 2];
+    let mut input = "in".to_string();
+    let mut time = false;
+    let mut draw = false;
+    let mut trace = false;
+    let mut threads = None;
+    let mut run_length = None;
+    let mut svg = None;
+    let mut dot = None;
+    let mut mermaid = None;
+    let mut presses = None;
+
+    while let Some(arg) = args.next() {
+        // Flags without a value
+        match arg.as_str() {
+            "--time" => {
+                time = true;
+                continue;
+            }
+            "--draw" => {
+                draw = true;
+                continue;
+            }
+            "--trace" => {
+                trace = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for `{arg}`"))?;
+
+        match arg.as_str() {
+            "--part" => {
+                parts = match value.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => return Err(format!("Invalid part `{value}`").into()),
+                }
+            }
+            "--input" => input = value.to_string(),
+            "--threads" => {
+                threads = Some(
+                    value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|threads| *threads > 0)
+                        .ok_or_else(|| format!("Invalid thread count `{value}`"))?,
+                )
+            }
+            "--run-length" => run_length = Some(parse_run_length(value)?),
+            "--svg" => svg = Some(value.to_string()),
+            "--dot" => dot = Some(value.to_string()),
+            "--mermaid" => mermaid = Some(value.to_string()),
+            "--presses" => {
+                presses = Some(
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid press count `{value}`"))?,
+                )
+            }
+            _ => return Err(format!("Unknown option `{arg}`").into()),
+        }
+    }
+
+    if days.len() > 1 && named_input(&input).is_none() {
+        return Err("Running all days needs a named input".into());
+    }
+    if draw && !days.iter().all(|day| DRAWN_DAYS.contains(day)) {
+        return Err(format!("`--draw` only applies to days {DRAWN_DAYS:?}").into());
+    }
+    if (trace || threads.is_some()) && days != [16] {
+        return Err("`--trace` and `--threads` only apply to day 16".into());
+    }
+    if run_length.is_some() && days != [17] {
+        return Err("`--run-length` only applies to day 17".into());
+    }
+    if svg.is_some() && days != [18] {
+        return Err("`--svg` only applies to day 18".into());
+    }
+    if dot.is_some() && days != [20] && days != [23] {
+        return Err("`--dot` only applies to days 20 and 23".into());
+    }
+    if (mermaid.is_some() || presses.is_some()) && days != [20] {
+        return Err("`--mermaid` and `--presses` only apply to day 20".into());
+    }
+    if presses.is_some() && dot.is_none() && mermaid.is_none() {
+        return Err("`--presses` needs `--dot` or `--mermaid`".into());
+    }
+    if (dot.is_some() || mermaid.is_some()) && days == [20] && parts == [2] {
+        return Err("Day 20 part 1 writes the drawings, not part 2".into());
+    }
+
+    Ok(Args {
+        days,
+        parts,
+        input,
+        time,
+        draw,
+        trace,
+        threads,
+        run_length,
+        svg,
+        dot,
+        mermaid,
+        presses,
+    })
+}
+
+// `4-10`, at least one block
+fn parse_run_length(value: &str) -> Res<(usize, usize)> {
+    value
+        .split_once('-')
+        .and_then(|(min, max)| Some((min.parse::<usize>().ok()?, max.parse::<usize>().ok()?)))
+        .filter(|(min, max)| (1..=*max).contains(min))
+        .ok_or_else(|| format!("Invalid run length `{value}`, expected `<min>-<max>`").into())
+}
+
+// `in` and `in.txt` both name `assets/dayN/in.txt`
+fn named_input(input: &str) -> Option<&str> {
+    let name = input.strip_suffix(".txt").unwrap_or(input);
+
+    NAMED_INPUTS.contains(&name).then_some(name)
+}
+
+// `-` is passed through and read from stdin
+fn input_path(day: usize, input: &str) -> String {
+    match named_input(input) {
+        Some(name) => asset(day, name),
+        None => input.to_string(),
+    }
+}
+
+// Shows the offending line with a caret under the column, like rustc does
+fn diagnostic(file: &str, text: &str, err: &ParseError) -> String {
+    let file = if file == "-" { "<stdin>" } else { file };
+    let line = text.lines().nth(err.line - 1).unwrap_or_default();
+    let number = err.line.to_string();
+    let pad = " ".repeat(number.len());
+    let caret = "^".repeat(err.text.chars().count().max(1));
+    let found = if err.text.is_empty() {
+        String::new()
+    } else {
+        format!(" `{}`", err.text)
+    };
+
+    format!(
+        "error: day {}: {}{found}\n{pad}--> {file}:{}:{}\n{pad} |\n{number} | {line}\n{pad} | {}{caret}",
+        err.day,
+        err.reason,
+        err.line,
+        err.column,
+        " ".repeat(err.column - 1),
+    )
+}
+
+// The days with options of their own, `None` to use the one in `DAYS`
+fn configure(day: usize, args: &Args) -> Option<Box<dyn DynSolution>> {
+    match day {
+        10 => Some(Box::new(Day10 { draw: args.draw })),
+        16 => Some(Box::new(Day16 {
+            draw: args.draw,
+            trace: args.trace,
+            threads: args.threads,
+        })),
+        17 => Some(Box::new(Day17 {
+            runs: args.run_length.map_or(Day17::PUZZLE.runs, |runs| [runs; 2]),
+            draw: args.draw,
+        })),
+        18 => Some(Box::new(Day18 {
+            svg: args.svg.clone(),
+        })),
+        20 => Some(Box::new(Day20 {
+            dot: args.dot.clone(),
+            mermaid: args.mermaid.clone(),
+            presses: args.presses,
+        })),
+        23 => Some(Box::new(Day23 {
+            dot: args.dot.clone(),
+        })),
+        _ => None,
+    }
+}
+
+fn print_timings(timings: &[Timing]) {
+    let cell = |duration: Option<Duration>| match duration {
+        Some(duration) => format!("{duration:.2?}"),
+        None => "-".to_string(),
+    };
+
+    println!();
+    println!(
+        "{:>4} | {:>10} | {:>10} | {:>10} | {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    println!(
+        "{:-<4}-+-{:-<10}-+-{:-<10}-+-{:-<10}-+-{:-<10}",
+        "", "", "", "", ""
+    );
+
+    for timing in timings {
+        println!(
+            "{:>4} | {:>10} | {:>10} | {:>10} | {:>10}",
+            timing.day,
+            cell(Some(timing.parse)),
+            cell(timing.parts[0]),
+            cell(timing.parts[1]),
+            cell(Some(timing.total())),
+        );
+    }
+
+    if timings.len() > 1 {
+        let total = timings.iter().map(Timing::total).sum::<Duration>();
+
+        println!(
+            "{:>4} | {:>10} | {:>10} | {:>10} | {:>10}",
+            "All",
+            "",
+            "",
+            "",
+            cell(Some(total))
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    let mut timings = vec![];
+
+    for &day in args.days.iter() {
+        let file = input_path(day, &args.input);
+        let configured = configure(day, &args);
+        let solution = configured.as_deref().unwrap_or(DAYS[day - 1]);
+
+        // Not every day has its puzzle input checked in, `tests/answers.rs` counts those as pending too
+        if named_input(&args.input).is_some() && !Path::new(&file).exists() {
+            println!("Day {day}: pending, no {file}");
+            continue;
+        }
+
+        let text = match read_input(&file) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("Day {day}: {file}: {err}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let start = Instant::now();
+        let input = match solution.parse(&text) {
+            Ok(input) => input,
+            Err(err) => {
+                match err.downcast_ref::<ParseError>() {
+                    Some(err) => eprintln!("{}", diagnostic(&file, &text, err)),
+                    None => eprintln!("Day {day}: {file}: {err}"),
+                }
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut timing = Timing {
+            day,
+            parse: start.elapsed(),
+            parts: [None, None],
+        };
+
+        for &part in args.parts.iter() {
+            let start = Instant::now();
+            let ans = match part {
+                1 => solution.part1(&*input),
+                _ if solution.has_part2() => solution.part2(&*input),
+                _ => {
+                    println!("Day {day} has no part 2");
+                    continue;
+                }
+            };
+            let elapsed = start.elapsed();
+
+            match ans {
+                Ok(ans) => {
+                    println!("Day {day} part {part}: {ans}");
+                    timing.parts[part - 1] = Some(elapsed);
+                }
+                Err(err) => {
+                    eprintln!("Day {day} part {part}: {file}: {err}");
+                    failed = true;
+                }
+            }
+        }
+
+        timings.push(timing);
+    }
+
+    if args.time {
+        print_timings(&timings);
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    input::{to_lines, Res},
+    solution::Solution,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        Ok(to_lines(text))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input
+            .iter()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .filter_map(|line| {
+                let first = line.iter().find(|c| c.is_ascii_digit())?.to_digit(10)?;
+                let last = line
+                    .iter()
+                    .rev()
+                    .find(|c| c.is_ascii_digit())?
+                    .to_digit(10)?;
+
+                Some(first * 10 + last)
+            })
+            .reduce(|acc, x| acc + x)
+            .unwrap_or(1))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        const VALID_DIGITS: &[&str] = &[
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+
+        let valid_digits_dir = VALID_DIGITS
+            .iter()
+            .map(|digit| digit.to_string())
+            .collect::<Vec<_>>();
+
+        let valid_digits_rev = VALID_DIGITS
+            .iter()
+            .map(|digit| digit.to_string().chars().rev().collect::<String>())
+            .collect::<Vec<_>>();
+
+        fn get_digit_str(digits: &[String], line: &str) -> Option<(usize, usize)> {
+            // pos, digit
+            let mut digits = digits
+                .iter()
+                .enumerate()
+                .filter_map(|(ind, digit)| Some((line.find(digit)?, ind + 1)))
+                .collect::<Vec<_>>();
+
+            digits.sort_unstable();
+            digits.first().copied()
+        }
+
+        fn get_digit(line: &str) -> Option<(usize, usize)> {
+            let pos = line.chars().position(|c| c.is_ascii_digit())?;
+            Some((pos, line.chars().nth(pos)?.to_digit(10)? as usize))
+        }
+
+        Ok(input
+            .iter()
+            .filter_map(|line| {
+                let line_rev = line.chars().rev().collect::<String>();
+                let mut digits = vec![
+                    get_digit_str(&valid_digits_dir, line),
+                    get_digit_str(&valid_digits_rev, &line_rev)
+                        .map(|x| (line_rev.len() - 1 - x.0, x.1)),
+                    get_digit(line),
+                    get_digit(&line_rev).map(|x| (line_rev.len() - 1 - x.0, x.1)),
+                ]
+                .into_iter()
+                .filter(|x| x.is_some())
+                .collect::<Vec<_>>();
+
+                digits.sort_unstable();
+                if let (Some(first), Some(last)) = (digits.first()?, digits.last()?) {
+                    Some(first.1 * 10 + last.1)
+                } else {
+                    None
+                }
+            })
+            .reduce(|acc, x| acc + x)
+            .unwrap_or(1) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day1;
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+    #[test]
+    fn parse_reader_works() {
+        let lines = Day1.parse_reader(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(lines, Day1.parse(EXAMPLE).unwrap());
+        assert_eq!(Day1.part2(&lines).unwrap(), 281);
+    }
+}
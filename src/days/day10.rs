@@ -0,0 +1,308 @@
+use crate::{
+    direction::Direction,
+    geometry::polygon,
+    grid::{Grid, Point},
+    input::{ParseError, Res},
+    solution::Solution,
+};
+
+// Which of up, right, down and left every pipe connects to, by `Direction::index`
+type Matrix = Grid<[bool; 4]>;
+
+const PIPES: &[(char, [bool; 4])] = &[
+    ('|', [true, false, true, false]),
+    ('-', [false, true, false, true]),
+    ('L', [true, true, false, false]),
+    ('J', [true, false, false, true]),
+    ('7', [false, false, true, true]),
+    ('F', [false, true, true, false]),
+];
+
+#[derive(Debug)]
+pub struct Maze {
+    pub pipes: Matrix,
+    pub start: Point,
+    // The pipe hidden under `S`
+    pub start_pipe: char,
+    // Every tile of the loop in walking order, `start` first
+    pub path: Vec<Point>,
+}
+
+fn parse_input(text: &str) -> Res<Maze> {
+    let cells = Grid::parse(10, text, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+
+    let start = cells
+        .position(|c| c == &'S')
+        .ok_or_else(|| ParseError::missing(10, text, "start `S`"))?;
+
+    let mut pipes: Matrix = cells.map(|c| {
+        PIPES
+            .iter()
+            .find_map(|(pipe, connects)| (pipe == c).then_some(*connects))
+            .unwrap_or_default()
+    });
+
+    // Whichever way out of `S` comes back to it, it comes back through the other end of its pipe
+    let (path, first, last) = Direction::ALL
+        .into_iter()
+        .find_map(|first| {
+            let (path, last) = trace_loop(start, first, &pipes)?;
+            Some((path, first, last))
+        })
+        .ok_or_else(|| ParseError {
+            day: 10,
+            line: start.0 as usize + 1,
+            column: start.1 as usize + 1,
+            text: "S".to_string(),
+            reason: "start is not on a loop".to_string(),
+        })?;
+
+    let mut connects = [false; 4];
+    connects[first.index()] = true;
+    connects[last.reverse().index()] = true;
+    pipes[start] = connects;
+
+    let start_pipe = PIPES
+        .iter()
+        .find_map(|(pipe, pipe_connects)| (pipe_connects == &connects).then_some(*pipe))
+        .expect("a loop leaves and enters `S` from two different sides");
+
+    Ok(Maze {
+        pipes,
+        start,
+        start_pipe,
+        path,
+    })
+}
+
+// Follows the pipes leaving `start` towards `first` until they come back to it. Returns the tiles
+// on the way and the direction of the last step, `None` when the pipes lead nowhere.
+fn trace_loop(start: Point, first: Direction, pipes: &Matrix) -> Option<(Vec<Point>, Direction)> {
+    let mut path = vec![start];
+    let mut cur = start;
+    let mut dir = first;
+
+    // Every pipe has two ends, so the walk either gets back to `start` or gets stuck
+    loop {
+        cur = dir.step(cur);
+        if cur == start {
+            return Some((path, dir));
+        }
+
+        let pipe = pipes.get(cur)?;
+        if !pipe[dir.reverse().index()] {
+            return None;
+        }
+
+        dir = Direction::ALL
+            .into_iter()
+            .find(|out| *out != dir.reverse() && pipe[out.index()])?;
+        path.push(cur);
+    }
+}
+
+fn on_path(maze: &Maze) -> Grid<bool> {
+    maze.path.iter().fold(
+        Grid::new(maze.pipes.rows(), maze.pipes.cols(), false),
+        |mut on_path, point| {
+            on_path[*point] = true;
+            on_path
+        },
+    )
+}
+
+// The loop as a polygon through the centers of its tiles
+fn vertices(maze: &Maze) -> Vec<(i64, i64)> {
+    maze.path
+        .iter()
+        .map(|&(i, j)| (i as i64, j as i64))
+        .collect()
+}
+
+// Where a tile is relative to the loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Loop,
+    Inside,
+    Outside,
+}
+
+// Scans every row left to right: a tile is inside once the loop was crossed an odd number of times
+fn classify(mtx: &Matrix, on_path: &Grid<bool>) -> Grid<Tile> {
+    let mut tiles = Grid::new(mtx.rows(), mtx.cols(), Tile::Outside);
+
+    for (i, row) in mtx.iter_rows().enumerate() {
+        let mut enclosed = false;
+        let mut in_down = false;
+        let mut in_up = false;
+        for (j, pipe) in row.iter().enumerate() {
+            let point = (i as i32, j as i32);
+            if on_path[point] {
+                let crossed = match pipe {
+                    /* '.' => */ [false, false, false, false] => false,
+                    /* '|' => */ [true, false, true, false] => true,
+                    /* '-' => */ [false, true, false, true] => false,
+                    /* 'L' => */
+                    [true, true, _, _] => {
+                        in_up = true;
+                        false
+                    }
+                    /* 'J' => */
+                    [true, _, _, true] => {
+                        if in_up {
+                            in_up = false;
+                            false
+                        } else {
+                            assert!(in_down);
+                            in_down = false;
+                            true
+                        }
+                    }
+                    /* 'F' => */
+                    [_, true, true, _] => {
+                        in_down = true;
+                        false
+                    }
+                    /* '7' => */
+                    [_, _, true, true] => {
+                        if in_down {
+                            in_down = false;
+                            false
+                        } else {
+                            assert!(in_up);
+                            in_up = false;
+                            true
+                        }
+                    }
+
+                    _ => unreachable!(),
+                };
+
+                enclosed ^= crossed;
+                tiles[point] = Tile::Loop;
+            } else if enclosed {
+                tiles[point] = Tile::Inside;
+            }
+        }
+    }
+
+    tiles
+}
+
+// The loop in box drawing characters, every other tile as `I` or `O`
+pub fn render(maze: &Maze, tiles: &Grid<Tile>) -> String {
+    let map = tiles.iter().fold(
+        Grid::new(tiles.rows(), tiles.cols(), ' '),
+        |mut map, (point, tile)| {
+            map[point] = match tile {
+                Tile::Inside => 'I',
+                Tile::Outside => 'O',
+                Tile::Loop => match maze.pipes[point] {
+                    [true, false, true, false] => '│',
+                    [false, true, false, true] => '─',
+                    [true, true, false, false] => '└',
+                    [true, false, false, true] => '┘',
+                    [false, false, true, true] => '┐',
+                    _ => '┌',
+                },
+            };
+            map
+        },
+    );
+
+    map.to_string()
+}
+
+pub struct Day10 {
+    // Print the maze with the loop and the enclosed tiles
+    pub draw: bool,
+}
+
+impl Solution for Day10 {
+    type Input = Maze;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input.path.len() / 2)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let maze = input;
+
+        if self.draw {
+            println!("{}", render(maze, &classify(&maze.pipes, &on_path(maze))));
+        }
+
+        // The enclosed tiles are the lattice points strictly inside the loop
+        Ok(polygon::interior_points(&vertices(maze)) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{classify, on_path, parse_input, render, vertices, Day10, Tile};
+    use crate::{
+        geometry::polygon::{self, Location},
+        solution::Solution,
+    };
+
+    #[test]
+    fn render_works() {
+        let maze = parse_input(
+            "..........\n\
+             .S------7.\n\
+             .|F----7|.\n\
+             .||....||.\n\
+             .||....||.\n\
+             .|L-7F-J|.\n\
+             .|..||..|.\n\
+             .L--JL--J.\n\
+             ..........",
+        )
+        .unwrap();
+
+        assert_eq!(maze.start_pipe, 'F');
+        assert_eq!(maze.path.len(), 44);
+        assert_eq!(Day10 { draw: false }.part2(&maze).unwrap(), 4);
+
+        // The scanline agrees with the polygon on every tile
+        let tiles = classify(&maze.pipes, &on_path(&maze));
+        let vertices = vertices(&maze);
+        for (point, tile) in tiles.iter() {
+            let location = match polygon::locate(&vertices, (point.0 as i64, point.1 as i64)) {
+                Location::Inside => Tile::Inside,
+                Location::Boundary => Tile::Loop,
+                Location::Outside => Tile::Outside,
+            };
+            assert_eq!(*tile, location);
+        }
+
+        assert_eq!(
+            render(&maze, &tiles),
+            "OOOOOOOOOO\n\
+             O┌──────┐O\n\
+             O│┌────┐│O\n\
+             O││OOOO││O\n\
+             O││OOOO││O\n\
+             O│└─┐┌─┘│O\n\
+             O│II││II│O\n\
+             O└──┘└──┘O\n\
+             OOOOOOOOOO"
+        );
+    }
+
+    #[test]
+    fn broken_loop_is_an_error() {
+        let err = parse_input("S-7\n|.|\nL-.").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "day 10, line 1, column 1: start is not on a loop `S`"
+        );
+    }
+}
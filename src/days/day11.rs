@@ -0,0 +1,75 @@
+use crate::{grid::Grid, input::Res, solution::Solution};
+
+pub type Position = (usize, usize);
+
+// galaxies, expanded rows, expanded columns
+pub type Universe = (Vec<Position>, Vec<usize>, Vec<usize>);
+
+fn parse_input(text: &str) -> Res<Universe> {
+    let grid = Grid::parse(11, text, |c| "#.".contains(c).then_some(c))?;
+
+    let expanded_rows = (0..grid.rows())
+        .filter(|i| grid.row(*i).iter().all(|c| c == &'.'))
+        .collect::<Vec<_>>();
+
+    let expanded_cols = (0..grid.cols())
+        .filter(|j| grid.column(*j).all(|c| c == &'.'))
+        .collect::<Vec<_>>();
+
+    let galaxies = grid
+        .iter()
+        .filter(|(_, c)| *c == &'#')
+        .map(|((i, j), _)| (i as usize, j as usize))
+        .collect::<Vec<_>>();
+
+    Ok((galaxies, expanded_rows, expanded_cols))
+}
+
+fn solve((galaxies, expanded_rows, expanded_cols): &Universe, k: u64) -> u64 {
+    galaxies
+        .iter()
+        .enumerate()
+        .map(|(galaxy_ind_1, galaxy_1)| {
+            galaxies
+                .iter()
+                .skip(galaxy_ind_1)
+                .map(|galaxy_2| {
+                    let (i_min, i_max) = (galaxy_1.0.min(galaxy_2.0), galaxy_1.0.max(galaxy_2.0));
+
+                    let er_left = expanded_rows.binary_search(&i_min).err().unwrap();
+                    let er_right = expanded_rows.binary_search(&i_max).err().unwrap();
+
+                    let (j_min, j_max) = (galaxy_1.1.min(galaxy_2.1), galaxy_1.1.max(galaxy_2.1));
+
+                    let ec_left = expanded_cols.binary_search(&j_min).err().unwrap();
+                    let ec_right = expanded_cols.binary_search(&j_max).err().unwrap();
+
+                    (i_max - i_min) as u64
+                        + (k - 1) * (er_right - er_left) as u64
+                        + (j_max - j_min) as u64
+                        + (k - 1) * (ec_right - ec_left) as u64
+                })
+                .sum::<u64>()
+        })
+        .sum::<u64>()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(solve(input, 2))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        Ok(solve(input, 1000000))
+    }
+}
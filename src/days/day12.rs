@@ -0,0 +1,131 @@
+use crate::{
+    input::{numbered, ParseError, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Vec<(Vec<u8>, Vec<usize>)>> {
+    Ok(numbered(12, text)
+        .map(|line| {
+            let mut line_iter = line.text.split_whitespace();
+
+            let group = line_iter.next().ok_or_else(|| line.missing("springs"))?;
+            line.check_chars(group, "#.?", "spring")?;
+            let group = group.bytes().collect::<Vec<_>>();
+
+            let spring_ranges = line_iter
+                .next()
+                .ok_or_else(|| line.missing("counts"))?
+                .split(',')
+                .map(|x| line.parse::<usize>(x, "count"))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((group, spring_ranges))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?)
+}
+
+fn is_valid_prefix(pattern: &[u8], cur_len: usize, to_add_len: usize) -> bool {
+    if pattern.len() < cur_len + to_add_len {
+        return false;
+    }
+    for ind in 0..(to_add_len - 1) {
+        let pattern_symb = &pattern[cur_len + ind];
+        if !((pattern_symb == &b'#') || pattern_symb == &b'?') {
+            return false;
+        }
+    }
+    let last = &pattern[cur_len + to_add_len - 1];
+    if !((last == &b'.') || (last == &b'?')) {
+        return false;
+    }
+    true
+}
+
+fn please_find_a_solution(
+    cur_len: usize,
+    cur_range_index: usize,
+    pattern: &Vec<u8>,
+    spring_ranges: &Vec<usize>,
+    dp: &mut Vec<Vec<Option<u64>>>,
+) -> u64 {
+    if let Some(ans) = dp[cur_len][cur_range_index] {
+        return ans;
+    }
+
+    if cur_len == pattern.len() {
+        return (cur_range_index == spring_ranges.len()) as u64;
+    }
+
+    let mut ans = 0;
+
+    if cur_range_index < spring_ranges.len() {
+        let next = spring_ranges[cur_range_index];
+
+        if cur_len + next < pattern.len() && is_valid_prefix(pattern, cur_len, next + 1) {
+            ans += please_find_a_solution(
+                cur_len + next + 1,
+                cur_range_index + 1,
+                pattern,
+                spring_ranges,
+                dp,
+            );
+        }
+    }
+
+    if is_valid_prefix(pattern, cur_len, 1) {
+        ans += please_find_a_solution(cur_len + 1, cur_range_index, pattern, spring_ranges, dp);
+    }
+
+    dp[cur_len][cur_range_index] = Some(ans);
+    ans
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Vec<u8>, Vec<usize>)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input
+            .clone()
+            .into_iter()
+            .map(|(mut group, spring_ranges)| {
+                group.push(b'.');
+
+                let mut dp = vec![vec![None; spring_ranges.len() + 1]; group.len() + 1];
+
+                please_find_a_solution(0, 0, &group, &spring_ranges, &mut dp)
+            })
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        Ok(input
+            .clone()
+            .into_iter()
+            .map(|(mut group, mut spring_ranges)| {
+                group.push(b'?');
+                group = (0..5)
+                    .flat_map(|_| group.iter().copied())
+                    .collect::<Vec<_>>();
+
+                spring_ranges = (0..5)
+                    .flat_map(|_| spring_ranges.iter().copied())
+                    .collect::<Vec<_>>();
+
+                *group.last_mut().unwrap() = b'.';
+                group.push(b'.');
+
+                let mut dp = vec![vec![None; spring_ranges.len() + 1]; group.len() + 1];
+
+                please_find_a_solution(0, 0, &group, &spring_ranges, &mut dp)
+            })
+            .sum())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    grid::Grid,
+    input::{numbered, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Vec<Grid<char>>> {
+    let lines = numbered(13, text).collect::<Vec<_>>();
+
+    let patterns = lines
+        .split(|line| line.text.is_empty())
+        .filter(|pattern| !pattern.is_empty())
+        .map(|pattern| Grid::parse_rows(pattern, |c| "#.".contains(c).then_some(c)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(patterns)
+}
+
+fn solve(pattern: &Grid<char>, smudges: usize) -> usize {
+    let pattern_len = pattern.rows();
+    let line_len = pattern.cols();
+
+    let lines_indx = pattern
+        .iter_rows()
+        .map(|line| {
+            let m = line.len();
+
+            (1..m)
+                .map(|i| {
+                    let to_left = i;
+                    let to_right = m - i;
+
+                    let min = to_left.min(to_right);
+
+                    (0..min)
+                        .map(|i_| line[i - i_ - 1] == line[i + i_])
+                        .filter(|x| !x)
+                        .count()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    (0..(line_len - 1))
+        .filter_map(|line_indx| {
+            let smudges_cnt = (0..pattern_len)
+                .map(|pattern_index| lines_indx[pattern_index][line_indx])
+                .sum::<usize>();
+
+            (smudges_cnt == smudges).then_some(line_indx + 1)
+        })
+        .next()
+        .unwrap_or(0)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Grid<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let mut ans = 0;
+
+        for pattern in input.iter() {
+            ans += solve(pattern, 0);
+            ans += 100 * solve(&pattern.transpose(), 0);
+        }
+
+        Ok(ans)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let mut ans = 0;
+
+        for pattern in input.iter() {
+            ans += solve(pattern, 1);
+            ans += 100 * solve(&pattern.transpose(), 1);
+        }
+
+        Ok(ans)
+    }
+}
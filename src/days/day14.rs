@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::{grid::Grid, input::Res, solution::Solution};
+
+fn parse_input(text: &str) -> Res<Grid<char>> {
+    Ok(Grid::parse(14, text, |c| "O#.".contains(c).then_some(c))?)
+}
+
+// Rolls every round rock up until it hits a cube rock, another round one or the edge
+fn tilt(grid: &mut Grid<char>) {
+    for j in 0..grid.cols() as i32 {
+        let mut free = 0;
+
+        for i in 0..grid.rows() as i32 {
+            match grid[(i, j)] {
+                '#' => free = i + 1,
+                'O' => {
+                    grid[(i, j)] = '.';
+                    grid[(free, j)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn load(grid: &Grid<char>) -> usize {
+    grid.iter()
+        .filter(|(_, c)| *c == &'O')
+        .map(|((i, _), _)| grid.rows() - i as usize)
+        .sum()
+}
+
+// North, west, south, east: tilting north after each clockwise turn
+fn cycle(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+
+    for _ in 0..4 {
+        tilt(&mut grid);
+        grid = grid.rotate();
+    }
+
+    grid
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let mut grid = input.clone();
+        tilt(&mut grid);
+
+        Ok(load(&grid))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let many_many = 1_000_000_000;
+
+        let mut seen = HashMap::from([(input.clone(), 0)]);
+        let mut grids = vec![input.clone()];
+
+        // The rocks settle into a loop long before a billion cycles
+        loop {
+            let grid = cycle(&grids[grids.len() - 1]);
+            let iteration = grids.len();
+
+            if let Some(&first) = seen.get(&grid) {
+                let cycle_length = iteration - first;
+                let index = first + (many_many - first) % cycle_length;
+
+                return Ok(load(&grids[index]));
+            }
+
+            seen.insert(grid.clone(), iteration);
+            grids.push(grid);
+        }
+    }
+}
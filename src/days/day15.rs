@@ -0,0 +1,107 @@
+use crate::{
+    input::{numbered, ParseError, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Vec<String>> {
+    let line = numbered(15, text)
+        .next()
+        .ok_or_else(|| ParseError::missing(15, text, "initialization sequence"))?;
+
+    let steps = line
+        .text
+        .split(',')
+        .map(|step| {
+            match step.split_once(['-', '=']) {
+                Some((_, "")) if step.ends_with('-') => {}
+                Some((_, focal)) if step.contains('=') => {
+                    line.parse::<usize>(focal, "focal length")?;
+                }
+                _ => return Err(line.error(step, "expected `<label>=<focal>` or `<label>-`")),
+            }
+
+            Ok(step.to_owned())
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok(steps)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input
+            .iter()
+            .map(|step| {
+                step.chars().fold(0, |hash, c| {
+                    let c = (c as u8) as u32;
+                    17 * (hash + c) % 256
+                })
+            })
+            .sum::<u32>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        fn modify(
+            boxes: &mut [Vec<Option<(String, usize)>>],
+            hash: usize,
+            label: &str,
+            value: Option<(String, usize)>,
+        ) -> bool {
+            for len in &mut boxes[hash] {
+                if let Some(len_) = len {
+                    if len_.0 == label {
+                        *len = value;
+                        return true;
+                    }
+                }
+            }
+
+            false
+        }
+
+        let steps = input;
+        let mut boxes = vec![Vec::<Option<(String, usize)>>::new(); 256];
+
+        steps.iter().for_each(|step| {
+            let mut step = step.split(['-', '=']);
+
+            let label = step.next().expect("No label").to_string();
+            let focal = step.next().expect("No no no");
+
+            let hash = label.chars().fold(0, |hash, c| {
+                let c = (c as u8) as usize;
+                17 * (hash + c) % 256
+            });
+
+            if !focal.is_empty() {
+                let focal = focal.parse::<usize>().unwrap();
+
+                if !modify(&mut boxes, hash, &label, Some((label.clone(), focal))) {
+                    boxes[hash].push(Some((label, focal)));
+                }
+            } else {
+                let _ = modify(&mut boxes, hash, &label, None);
+            }
+        });
+
+        let mut ans = 0;
+        for (box_ind, box_) in boxes.into_iter().enumerate() {
+            let box_ = box_.into_iter().flatten().collect::<Vec<_>>();
+            for (len_ind, len) in box_.into_iter().enumerate() {
+                ans += (1 + box_ind) * (1 + len_ind) * len.1;
+            }
+        }
+
+        Ok(ans)
+    }
+}
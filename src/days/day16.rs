@@ -0,0 +1,274 @@
+use std::thread;
+
+use crate::{
+    direction::Direction::{self, Down, Left, Right, Up},
+    grid::{Grid, Point},
+    input::Res,
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Grid<char>> {
+    Ok(Grid::parse(16, text, |c| {
+        r"./\|-".contains(c).then_some(c)
+    })?)
+}
+
+// A beam just outside the grid, about to step in
+pub type Entry = (Point, Direction);
+
+pub struct Trace {
+    // Where the beams are after every step and the way they move, the first frame is the entry
+    pub frames: Vec<Vec<(Point, Direction)>>,
+    pub energized: Grid<bool>,
+}
+
+// Moves all beams one step at a time until none of them reach a new tile or direction
+pub fn trace(lines: &Grid<char>, start: Entry) -> Trace {
+    // The directions every cell was already entered from
+    let mut seen = Grid::new(lines.rows(), lines.cols(), [false; 4]);
+    let mut frames = vec![];
+    let mut beams = vec![start];
+
+    while !beams.is_empty() {
+        let mut next_beams = vec![];
+
+        for &(pos, dir) in &beams {
+            let next = dir.step(pos);
+            let Some(cell) = lines.get(next) else {
+                continue;
+            };
+
+            if seen[next][dir.index()] {
+                continue;
+            }
+            seen[next][dir.index()] = true;
+
+            match cell {
+                '.' => {
+                    next_beams.push((next, dir));
+                }
+                '|' => match dir {
+                    Left | Right => {
+                        next_beams.push((next, Up));
+                        next_beams.push((next, Down));
+                    }
+                    _ => next_beams.push((next, dir)),
+                },
+                '-' => match dir {
+                    Up | Down => {
+                        next_beams.push((next, Left));
+                        next_beams.push((next, Right));
+                    }
+                    _ => next_beams.push((next, dir)),
+                },
+                '/' => match dir {
+                    Up | Down => next_beams.push((next, dir.turn_right())),
+                    Left | Right => next_beams.push((next, dir.turn_left())),
+                },
+                '\\' => match dir {
+                    Up | Down => next_beams.push((next, dir.turn_left())),
+                    Left | Right => next_beams.push((next, dir.turn_right())),
+                },
+                _ => unreachable!(),
+            }
+        }
+
+        frames.push(beams);
+        beams = next_beams;
+    }
+
+    Trace {
+        frames,
+        energized: seen.map(|dirs| dirs.iter().any(|x| *x)),
+    }
+}
+
+fn solve(lines: &Grid<char>, start: Entry) -> usize {
+    trace(lines, start)
+        .energized
+        .iter()
+        .filter(|(_, energized)| **energized)
+        .count()
+}
+
+// Every tile of the border, entered straight from outside
+fn entries(lines: &Grid<char>) -> Vec<Entry> {
+    let n = lines.rows() as i32;
+    let m = lines.cols() as i32;
+
+    (0..n)
+        .flat_map(|i| [((i, -1), Right), ((i, m), Left)])
+        .chain((0..m).flat_map(|j| [((-1, j), Down), ((n, j), Up)]))
+        .collect()
+}
+
+// The entry that energizes the most tiles, the first one on ties
+fn best_of(lines: &Grid<char>, entries: &[Entry]) -> Option<(Entry, usize)> {
+    entries
+        .iter()
+        .map(|&start| (start, solve(lines, start)))
+        .rev()
+        .max_by_key(|(_, energized)| *energized)
+}
+
+// Same as `best_of` over the whole border, each of `threads` threads trying a slice of it
+pub fn best_entry(lines: &Grid<char>, threads: usize) -> Option<(Entry, usize)> {
+    let entries = entries(lines);
+    if threads <= 1 {
+        return best_of(lines, &entries);
+    }
+
+    let chunk_size = entries.len().div_ceil(threads).max(1);
+
+    thread::scope(|scope| {
+        let handles = entries
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || best_of(lines, chunk)))
+            .collect::<Vec<_>>();
+
+        // The chunks are in border order, so ties still go to the first entry
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().expect("a beam thread panicked"))
+            .rev()
+            .max_by_key(|(_, energized)| *energized)
+    })
+}
+
+// E.g. `left edge, row 3`
+pub fn describe((point, dir): Entry) -> String {
+    match dir {
+        Right => format!("left edge, row {}", point.0),
+        Left => format!("right edge, row {}", point.0),
+        Down => format!("top edge, column {}", point.1),
+        Up => format!("bottom edge, column {}", point.1),
+    }
+}
+
+// `#` for energized tiles
+pub fn render(energized: &Grid<bool>) -> String {
+    energized.map(|x| if *x { '#' } else { '.' }).to_string()
+}
+
+// One line per frame, every beam as `row,column` and the arrow of its direction
+pub fn render_frames(frames: &[Vec<(Point, Direction)>]) -> String {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(k, beams)| {
+            let beams = beams
+                .iter()
+                .map(|((i, j), dir)| format!("{i},{j}{}", dir.arrow()))
+                .collect::<Vec<_>>();
+
+            format!("frame {k}: {}", beams.join(" "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Day16 {
+    // Print the energized tiles
+    pub draw: bool,
+    // Print every step of the beams
+    pub trace: bool,
+    // Threads trying the entries of part 2, `None` for one per core
+    pub threads: Option<usize>,
+}
+
+impl Day16 {
+    fn show(&self, lines: &Grid<char>, start: Entry) {
+        if !self.draw && !self.trace {
+            return;
+        }
+
+        let trace = trace(lines, start);
+        if self.draw {
+            println!("{}", render(&trace.energized));
+        }
+        if self.trace {
+            println!("{}", render_frames(&trace.frames));
+        }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let lines = input;
+        let start = ((0, -1), Right);
+
+        self.show(lines, start);
+
+        Ok(solve(lines, start))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let lines = input;
+
+        let threads = self
+            .threads
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+
+        let (start, ans) =
+            best_entry(lines, threads).ok_or("There is no way into the contraption")?;
+
+        if self.draw || self.trace {
+            println!("Best entry: {}", describe(start));
+        }
+        self.show(lines, start);
+
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best_entry, describe, parse_input, render, trace};
+    use crate::{direction::Direction, input::read_input};
+
+    #[test]
+    fn trace_works() {
+        let lines = parse_input(&read_input("assets/day16/in_small.txt").unwrap()).unwrap();
+
+        let trace = trace(&lines, ((0, -1), Direction::Right));
+
+        // The illustration of the puzzle
+        assert_eq!(
+            render(&trace.energized),
+            "######....\n\
+             .#...#....\n\
+             .#...#####\n\
+             .#...##...\n\
+             .#...##...\n\
+             .#...##...\n\
+             .#..####..\n\
+             ########..\n\
+             .#######..\n\
+             .#...#.#.."
+        );
+        assert_eq!(trace.frames[0], [((0, -1), Direction::Right)]);
+        assert_eq!(trace.frames[1], [((0, 0), Direction::Right)]);
+
+        let (start, energized) = best_entry(&lines, 1).unwrap();
+        assert_eq!(energized, 51);
+        assert_eq!(describe(start), "top edge, column 3");
+    }
+
+    #[test]
+    fn threads_agree() {
+        let lines = parse_input(&read_input("assets/day16/in.txt").unwrap()).unwrap();
+
+        let serial = best_entry(&lines, 1);
+        for threads in [2, 3, 8, 1000] {
+            assert_eq!(best_entry(&lines, threads), serial);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    direction::Direction,
+    graph::dijkstra,
+    grid::{Grid, Point},
+    input::Res,
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Grid<i32>> {
+    Ok(Grid::parse(17, text, |c| c.to_digit(10).map(|x| x as i32))?)
+}
+
+// Where a straight run ended and the direction it went, nothing yet at the start
+type State = (Point, Option<Direction>);
+
+// Every block the crucible enters and the direction it moves in there
+pub type Route = Vec<(Point, Direction)>;
+
+// Least heat lost going from the top left to the bottom right corner when every straight run
+// is `min_run..=max_run` blocks long, and the route taken
+pub fn min_heat_loss(lines: &Grid<i32>, min_run: usize, max_run: usize) -> Option<(i32, Route)> {
+    let target = (lines.rows() as i32 - 1, lines.cols() as i32 - 1);
+
+    let (loss, corners) = dijkstra(
+        ((0, 0), None),
+        |&(cur, last): &State| {
+            let mut next = vec![];
+
+            // Every run turns, so a state only needs to know the direction of the last one
+            for dir in Direction::ALL {
+                if last.is_some_and(|last| dir == last || dir == last.reverse()) {
+                    continue;
+                }
+
+                let mut loss = 0;
+                for steps in 1..=max_run {
+                    let to = dir.step_by(cur, steps as i32);
+                    let Some(cell) = lines.get(to) else {
+                        break;
+                    };
+
+                    loss += cell;
+                    if steps >= min_run {
+                        next.push(((to, Some(dir)), loss));
+                    }
+                }
+            }
+
+            next
+        },
+        |&(cur, _)| cur == target,
+    )?;
+
+    // Fill in the blocks between the ends of consecutive runs
+    let route = corners
+        .windows(2)
+        .flat_map(|run| {
+            let ((from, _), (to, dir)) = (run[0], run[1]);
+            let dir = dir.expect("only the start has no direction");
+            let steps = (to.0 - from.0).abs() + (to.1 - from.1).abs();
+
+            (1..=steps).map(move |step| (dir.step_by(from, step), dir))
+        })
+        .collect();
+
+    Some((loss, route))
+}
+
+// The map with the route drawn over it, like the illustrations of the puzzle
+pub fn render(lines: &Grid<i32>, route: &Route) -> String {
+    let mut map = lines.map(|loss| char::from_digit(*loss as u32, 10).unwrap_or('?'));
+    for &(point, dir) in route {
+        map[point] = dir.arrow();
+    }
+
+    map.to_string()
+}
+
+pub struct Day17 {
+    // Shortest and longest straight run of the crucible, for part 1 and part 2
+    pub runs: [(usize, usize); 2],
+    // Print the map with the route before the answer
+    pub draw: bool,
+}
+
+impl Day17 {
+    pub const PUZZLE: Day17 = Day17 {
+        runs: [(1, 3), (4, 10)],
+        draw: false,
+    };
+
+    fn solve(&self, lines: &Grid<i32>, part: usize) -> Res<i32> {
+        let (min_run, max_run) = self.runs[part - 1];
+
+        let (loss, route) =
+            min_heat_loss(lines, min_run, max_run).ok_or("There is no way to the factory")?;
+
+        if self.draw {
+            println!("{}", render(lines, &route));
+        }
+
+        Ok(loss)
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Grid<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        self.solve(input, 1)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        self.solve(input, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{min_heat_loss, parse_input, render};
+    use crate::input::read_input;
+
+    #[test]
+    fn route_works() {
+        let lines = parse_input(&read_input("assets/day17/in_small.txt").unwrap()).unwrap();
+
+        let (loss, route) = min_heat_loss(&lines, 1, 3).unwrap();
+
+        assert_eq!(loss, 102);
+        assert_eq!(
+            route.iter().map(|(point, _)| lines[*point]).sum::<i32>(),
+            loss
+        );
+        // The illustration of the puzzle
+        assert_eq!(
+            render(&lines, &route),
+            "2>>34^>>>1323\n\
+             32v>>>35v5623\n\
+             32552456v>>54\n\
+             3446585845v52\n\
+             4546657867v>6\n\
+             14385987984v4\n\
+             44578769877v6\n\
+             36378779796v>\n\
+             465496798688v\n\
+             456467998645v\n\
+             12246868655<v\n\
+             25465488877v5\n\
+             43226746555v>"
+        );
+    }
+}
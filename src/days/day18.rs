@@ -0,0 +1,225 @@
+use std::{fmt::Write, fs};
+
+use crate::{
+    direction::Direction,
+    geometry::polygon,
+    input::{numbered, part_path, Line, ParseError, Res},
+    solution::Solution,
+};
+
+// The last hex digit of the color, in the order the puzzle numbers them
+const HEX_DIRS: &[Direction; 4] = &[
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+// Width and height the drawing is scaled to, in pixels
+const SVG_SIZE: f64 = 800.0;
+const SVG_MARGIN: f64 = 10.0;
+
+// One line of the dig plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub dir: Direction,
+    pub distance: i128,
+    // `#rrggbb` the trench is painted with
+    pub color: String,
+}
+
+// The six hex digits in `(#rrggbb)`
+fn color<'a>(line: &Line<'a>) -> Result<&'a str, ParseError> {
+    let color = line
+        .text
+        .split_whitespace()
+        .nth(2)
+        .ok_or_else(|| line.missing("color"))?;
+
+    color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| line.error(color, "expected `(#rrggbb)`"))
+}
+
+fn parse_input1(lines: &[Line]) -> Result<Vec<Step>, ParseError> {
+    lines
+        .iter()
+        .map(|line| {
+            let mut line_iter = line.text.split_whitespace();
+
+            let dir = line_iter.next().ok_or_else(|| line.missing("direction"))?;
+            let dir = dir
+                .parse::<char>()
+                .ok()
+                .and_then(Direction::from_letter)
+                .ok_or_else(|| line.error(dir, "unknown direction"))?;
+            let value = line_iter.next().ok_or_else(|| line.missing("distance"))?;
+            let value = line.parse::<i128>(value, "distance")?;
+
+            Ok(Step {
+                dir,
+                distance: value,
+                color: format!("#{}", color(line)?),
+            })
+        })
+        .collect()
+}
+
+// The color is the real instruction: five hex digits of distance and one of direction
+fn parse_input2(lines: &[Line]) -> Result<Vec<Step>, ParseError> {
+    lines
+        .iter()
+        .map(|line| {
+            let hex = color(line)?;
+
+            let value = i128::from_str_radix(&hex[..5], 16)
+                .map_err(|_| line.error(&hex[..5], "invalid hex distance"))?;
+            let dir = usize::from_str_radix(&hex[5..], 16)
+                .ok()
+                .and_then(|dir| HEX_DIRS.get(dir))
+                .ok_or_else(|| line.error(&hex[5..], "unknown direction"))?;
+
+            Ok(Step {
+                dir: *dir,
+                distance: value,
+                color: format!("#{hex}"),
+            })
+        })
+        .collect()
+}
+
+// Corners of the trench, starting and ending at the origin
+fn corners(plan: &[Step]) -> Vec<(i128, i128)> {
+    plan.iter()
+        .scan((0, 0), |(i, j), step| {
+            let (di, dj) = step.dir.offset();
+            (*i, *j) = (
+                *i + step.distance * di as i128,
+                *j + step.distance * dj as i128,
+            );
+            Some((*i, *j))
+        })
+        .collect()
+}
+
+// The trench is one cube wide, so the lagoon is every lattice point inside or on the polygon
+fn solve(plan: &[Step]) -> i128 {
+    let corners = corners(plan);
+
+    polygon::interior_points(&corners) + polygon::boundary_points(&corners)
+}
+
+// The lagoon scaled to fit `SVG_SIZE`, every edge of the trench in its own color
+pub fn svg(plan: &[Step]) -> String {
+    let corners = corners(plan);
+
+    let min_i = corners.iter().map(|c| c.0).min().unwrap_or(0);
+    let max_i = corners.iter().map(|c| c.0).max().unwrap_or(0);
+    let min_j = corners.iter().map(|c| c.1).min().unwrap_or(0);
+    let max_j = corners.iter().map(|c| c.1).max().unwrap_or(0);
+    let scale = SVG_SIZE / (max_i - min_i).max(max_j - min_j).max(1) as f64;
+
+    // Columns go right and rows go down, just like in SVG
+    let to_svg = |(i, j): (i128, i128)| {
+        (
+            (j - min_j) as f64 * scale + SVG_MARGIN,
+            (i - min_i) as f64 * scale + SVG_MARGIN,
+        )
+    };
+    let (width, height) = to_svg((max_i, max_j));
+
+    let mut out = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0:.0}" height="{1:.0}" viewBox="0 0 {0:.0} {1:.0}">"#,
+        width + SVG_MARGIN,
+        height + SVG_MARGIN
+    );
+    out.push('\n');
+
+    let points = corners
+        .iter()
+        .map(|&corner| {
+            let (x, y) = to_svg(corner);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>();
+    writeln!(
+        out,
+        r##"  <polygon points="{}" fill="#eeeeee"/>"##,
+        points.join(" ")
+    )
+    .unwrap();
+
+    let starts = [(0, 0)].into_iter().chain(corners.iter().copied());
+    for ((from, to), step) in starts.zip(corners.iter().copied()).zip(plan) {
+        let ((x1, y1), (x2, y2)) = (to_svg(from), to_svg(to));
+        writeln!(
+            out,
+            r#"  <line x1="{x1:.1}" y1="{y1:.1}" x2="{x2:.1}" y2="{y2:.1}" stroke="{}" stroke-width="2" stroke-linecap="square"/>"#,
+            step.color
+        )
+        .unwrap();
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+pub struct Day18 {
+    // Where to write the drawing of each part, `lagoon.svg` becomes `lagoon-1.svg` and `lagoon-2.svg`
+    pub svg: Option<String>,
+}
+
+impl Day18 {
+    fn solve(&self, plan: &[Step], part: usize) -> Res<i128> {
+        if let Some(path) = &self.svg {
+            let path = part_path(path, part);
+            fs::write(&path, svg(plan)).map_err(|err| format!("{path}: {err}"))?;
+        }
+
+        Ok(solve(plan))
+    }
+}
+
+impl Solution for Day18 {
+    type Input = (Vec<Step>, Vec<Step>);
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        let lines = numbered(18, text).collect::<Vec<_>>();
+
+        Ok((parse_input1(&lines)?, parse_input2(&lines)?))
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        self.solve(&input.0, 1)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        self.solve(&input.1, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{svg, Day18};
+    use crate::{input::read_input, solution::Solution};
+
+    #[test]
+    fn svg_works() {
+        let (plan, _) = Day18 { svg: None }
+            .parse(&read_input("assets/day18/in_small.txt").unwrap())
+            .unwrap();
+
+        let svg = svg(&plan);
+
+        assert!(svg.starts_with("<svg "));
+        assert_eq!(svg.matches("<line ").count(), plan.len());
+        // The first edge goes 6 right from the top left corner
+        assert!(
+            svg.contains(r##"<line x1="10.0" y1="10.0" x2="543.3" y2="10.0" stroke="#70c710""##)
+        );
+    }
+}
@@ -0,0 +1,378 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Part {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        match str {
+            "x" => Ok(Part::X),
+            "m" => Ok(Part::M),
+            "a" => Ok(Part::A),
+            "s" => Ok(Part::S),
+            _ => Err(format!("Unknown part `{str}`")),
+        }
+    }
+}
+
+impl Part {
+    fn indx(&self) -> usize {
+        match &self {
+            Part::X => 0,
+            Part::M => 1,
+            Part::A => 2,
+            Part::S => 3,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum RuleType {
+    Le,
+    Ge,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Rule {
+    pub t: RuleType,
+    pub part: Part,
+    pub value: i128,
+    pub next: String,
+}
+
+impl Rule {
+    fn reverse(&self) -> Self {
+        match self.t {
+            RuleType::Le => Rule {
+                value: self.value - 1,
+                t: RuleType::Ge,
+                next: self.next.clone(),
+                ..*self
+            },
+            RuleType::Ge => Rule {
+                value: self.value + 1,
+                t: RuleType::Le,
+                next: self.next.clone(),
+                ..*self
+            },
+        }
+    }
+
+    fn indx(&self) -> usize {
+        self.part.indx()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Range {
+    pub left: i128,
+    pub right: i128,
+}
+
+impl Range {
+    fn apply_rule(&self, rule: &Rule) -> Option<Self> {
+        match rule.t {
+            RuleType::Le => {
+                let right = self.right.min(rule.value - 1);
+                (self.left <= right).then_some(Range { right, ..*self })
+            }
+            RuleType::Ge => {
+                let left = self.left.max(rule.value + 1);
+                (left <= self.right).then_some(Range { left, ..*self })
+            }
+        }
+    }
+}
+
+impl Rule {
+    fn next(&self, item: &Item) -> Option<String> {
+        let item = item.get(&self.part).unwrap();
+
+        match &self.t {
+            RuleType::Le if item < &self.value => Some(self.next.clone()),
+            RuleType::Ge if item > &self.value => Some(self.next.clone()),
+
+            _ => None,
+        }
+    }
+}
+
+// name -> (rules, fallback)
+pub type Workflows = HashMap<String, (Vec<Rule>, String)>;
+pub type Item = HashMap<Part, i128>;
+
+fn parse_input(text: &str) -> Res<(Workflows, Vec<Item>)> {
+    let lines = numbered(19, text).collect::<Vec<_>>();
+
+    let mut block_iter = lines.split(|x| x.text.is_empty());
+
+    let mut rules: Workflows = HashMap::new();
+    // Every rule target and fallback as the line, the workflow it is in and the name
+    let mut targets = vec![];
+
+    let rules_iter = block_iter
+        .next()
+        .ok_or_else(|| ParseError::missing(19, text, "workflows"))?;
+    for line in rules_iter.iter() {
+        let (name, rule) = line.split_once("{")?;
+        let rule = rule.strip_suffix('}').ok_or_else(|| line.missing("`}`"))?;
+
+        let mut list = vec![];
+        let mut out = None;
+
+        for rule in rule.split(',') {
+            if let Some(out) = out {
+                return Err(line
+                    .error(out, "the fallback workflow should come last")
+                    .into());
+            }
+
+            if let Some((def, to)) = rule.split_once(':') {
+                let (part, value, t) = if let Some((part, value)) = def.split_once('<') {
+                    (part, value, RuleType::Le)
+                } else if let Some((part, value)) = def.split_once('>') {
+                    (part, value, RuleType::Ge)
+                } else {
+                    return Err(line.error(def, "expected a `<` or `>` comparison").into());
+                };
+
+                list.push(Rule {
+                    part: line.parse::<Part>(part, "part")?,
+                    value: line.parse::<i128>(value, "rating")?,
+                    next: to.to_string(),
+                    t,
+                });
+                targets.push((line, name, to));
+            } else {
+                out = Some(rule);
+            }
+        }
+        let out = out.ok_or_else(|| line.missing("fallback workflow"))?;
+        targets.push((line, name, out));
+
+        rules.insert(name.to_string(), (list, out.to_string()));
+    }
+
+    if !rules.contains_key("in") {
+        return Err(ParseError::missing(19, text, "workflow `in`").into());
+    }
+    for (line, _, to) in &targets {
+        if !matches!(*to, "A" | "R") && !rules.contains_key(*to) {
+            return Err(line.error(to, "unknown workflow").into());
+        }
+    }
+
+    // The target that leads back to a workflow still being walked, parts would never leave
+    fn find_loop<'a>(
+        cur: &'a str,
+        targets: &[(&Line, &'a str, &'a str)],
+        walking: &mut HashMap<&'a str, bool>,
+    ) -> Option<usize> {
+        walking.insert(cur, true);
+        for (i, (_, from, to)) in targets.iter().enumerate() {
+            if *from != cur {
+                continue;
+            }
+            match walking.get(to) {
+                Some(true) => return Some(i),
+                Some(false) => {}
+                None => {
+                    if let Some(i) = find_loop(to, targets, walking) {
+                        return Some(i);
+                    }
+                }
+            }
+        }
+        walking.insert(cur, false);
+
+        None
+    }
+
+    if let Some(i) = find_loop("in", &targets, &mut HashMap::new()) {
+        let (line, _, to) = targets[i];
+        return Err(line.error(to, "workflows loop back to").into());
+    }
+
+    let mut items = vec![];
+
+    let items_str = block_iter
+        .next()
+        .ok_or_else(|| ParseError::missing(19, text, "part ratings"))?;
+    for line in items_str {
+        let mut item_parts = HashMap::new();
+
+        let item = line.text.trim_matches(['{', '}'].as_slice());
+        for part in item.split(',') {
+            let (part, value) = part
+                .split_once('=')
+                .ok_or_else(|| line.error(part, "expected `<part>=<rating>`"))?;
+
+            item_parts.insert(
+                line.parse::<Part>(part, "part")?,
+                line.parse::<i128>(value, "rating")?,
+            );
+        }
+
+        if item_parts.len() != 4 {
+            return Err(line
+                .error(item, "expected ratings for x, m, a and s")
+                .into());
+        }
+
+        items.push(item_parts);
+    }
+
+    Ok((rules, items))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Workflows, Vec<Item>);
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (rules, items) = input;
+
+        fn dfs(cur: &String, rules: &Workflows, item: &Item) -> bool {
+            if let Some((rule, out)) = rules.get(cur) {
+                for rule_item in rule {
+                    if let Some(next) = rule_item.next(item) {
+                        return dfs(&next, rules, item);
+                    }
+                }
+                dfs(out, rules, item)
+            } else {
+                cur == "A"
+            }
+        }
+
+        Ok(items.iter().fold(0, |ans, item| {
+            ans + if dfs(&"in".to_string(), rules, item) {
+                item.values().sum::<i128>()
+            } else {
+                0
+            }
+        }))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (rules, _) = input;
+
+        fn dfs(cur: &String, rules: &Workflows, ranges: Vec<Range>) -> i128 {
+            let mut variants = ranges
+                .iter()
+                .fold(1, |pr, range| pr * (range.right - range.left + 1));
+
+            if let Some((rule, out)) = rules.get(cur) {
+                let mut ans = 0;
+
+                let mut cur_ranges = ranges.clone();
+
+                for rule_item in rule {
+                    let rule_item_index = rule_item.indx();
+                    let range = &cur_ranges[rule_item_index];
+
+                    if let Some(upd_range) = range.apply_rule(rule_item) {
+                        let mut new_limits = cur_ranges.clone();
+                        new_limits[rule_item_index] = upd_range;
+                        let to_add = dfs(&rule_item.next, rules, new_limits);
+                        ans += to_add;
+                    }
+
+                    // Every combination left took this rule, none reach the rest of the workflow
+                    let Some(rev_upd_limit) = range.apply_rule(&rule_item.reverse()) else {
+                        return ans;
+                    };
+                    variants = variants / (range.right - range.left + 1)
+                        * (rev_upd_limit.right - rev_upd_limit.left + 1);
+
+                    cur_ranges[rule_item_index] = rev_upd_limit;
+                }
+
+                // Variants for `out` (last)
+                ans += dfs(out, rules, cur_ranges);
+
+                ans
+            } else {
+                variants * ((cur == "A") as i128)
+            }
+        }
+
+        let ranges: Vec<Range> = vec![
+            // part: Part::X,
+            Range {
+                left: 1,
+                right: 4000,
+            },
+            // part: Part::M,
+            Range {
+                left: 1,
+                right: 4000,
+            },
+            // part: Part::A,
+            Range {
+                left: 1,
+                right: 4000,
+            },
+            // part: Part::S,
+            Range {
+                left: 1,
+                right: 4000,
+            },
+        ];
+
+        Ok(dfs(&"in".to_string(), rules, ranges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Day19};
+    use crate::{input::ParseError, solution::Solution};
+
+    #[test]
+    fn workflows_work() {
+        let err = parse_input("in{x<5:A}\n\n{x=1,m=2,a=3,s=4}").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(
+            (err.line, err.reason.as_str()),
+            (1, "missing fallback workflow")
+        );
+
+        // Every rating is below 5000, so the fallback is never reached
+        let input = parse_input("in{x<5000:A,R}\n\n{x=1,m=2,a=3,s=4}").unwrap();
+        assert_eq!(Day19.part1(&input).unwrap(), 10);
+        assert_eq!(Day19.part2(&input).unwrap(), 4000_i128.pow(4));
+
+        for (text, line, column, reason) in [
+            ("ab{x<5:A,R}", 4, 1, "missing workflow `in`"),
+            ("in{x>1:zz,R}", 1, 8, "unknown workflow"),
+            ("in{x<5:ab,A}\nab{m>3:in,R}", 2, 8, "workflows loop back to"),
+        ] {
+            let err = parse_input(&format!("{text}\n\n{{x=1,m=2,a=3,s=4}}")).unwrap_err();
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            assert_eq!(
+                (err.line, err.column, err.reason.as_str()),
+                (line, column, reason)
+            );
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    input::{numbered, ParseError, Res},
+    solution::Solution,
+};
+
+const CONS: &[(&str, u32)] = &[("red", 12), ("green", 13), ("blue", 14)];
+
+type TurnType = (u32, String); // count, color
+type Game = (u32, Vec<Vec<TurnType>>); // index, turns
+
+fn parse_game(text: &str) -> Res<Vec<Game>> {
+    let games = numbered(2, text)
+        .map(|line| {
+            let (game_ind, turns) = line.split_once(":")?;
+            let game_ind = game_ind
+                .strip_prefix("Game ")
+                .ok_or_else(|| line.error(game_ind, "expected `Game <index>`"))?;
+            let game_ind = line.parse::<u32>(game_ind, "game index")?;
+
+            let turns = turns
+                .split(';')
+                .map(|turn| {
+                    turn.split(',')
+                        .map(|cubes| {
+                            let cubes = cubes.trim();
+                            let (cnt, color) = cubes
+                                .split_once(' ')
+                                .ok_or_else(|| line.error(cubes, "expected `<count> <color>`"))?;
+                            let cnt = line.parse::<u32>(cnt, "count")?;
+
+                            Ok((cnt, color.to_string()))
+                        })
+                        .collect::<Result<Vec<_>, ParseError>>()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((game_ind, turns))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok(games)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_game(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input
+            .iter()
+            .filter_map(|(ind, game)| {
+                let games_flatten = game
+                    .iter()
+                    .flat_map(|game_turn| game_turn.iter())
+                    .collect::<Vec<_>>();
+
+                if CONS.iter().all(|(color, mx)| {
+                    games_flatten
+                        .iter()
+                        .filter_map(|game_| {
+                            if game_.1.as_str() == *color {
+                                Some(game_.0)
+                            } else {
+                                None
+                            }
+                        })
+                        .max()
+                        .unwrap_or(u32::MAX)
+                        <= *mx
+                }) {
+                    Some(ind)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        Ok(input
+            .iter()
+            .map(|(_, game)| {
+                let game_flat = game
+                    .iter()
+                    .flat_map(|game_turn| game_turn.iter())
+                    .collect::<Vec<_>>();
+
+                CONS.iter()
+                    .map(|(color, _)| {
+                        game_flat
+                            .iter()
+                            .filter_map(|game_| {
+                                if game_.1.as_str() == *color {
+                                    Some(game_.0)
+                                } else {
+                                    None
+                                }
+                            })
+                            .max()
+                            .unwrap_or(0)
+                    })
+                    .product::<u32>()
+            })
+            .sum())
+    }
+}
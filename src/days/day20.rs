@@ -0,0 +1,604 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+use std::fs;
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    math::lcm,
+    solution::Solution,
+};
+
+pub type NodeIndex = HashMap<String, usize>;
+
+fn parse_input(text: &str) -> Res<PulseNetwork> {
+    fn parse_node(line: &Line, str: &str) -> Result<Node, ParseError> {
+        let (t, name) = if let Some(name) = str.strip_prefix('%') {
+            (Type::FlipFlop(false), name)
+        } else if let Some(name) = str.strip_prefix('&') {
+            (Type::Conjunction(vec![]), name)
+        } else if str == "broadcaster" {
+            (Type::Start, str)
+        } else {
+            (Type::None, str)
+        };
+
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(line.error(str, "invalid module name"));
+        }
+
+        Ok(Node {
+            t,
+            name: name.to_owned(),
+        })
+    }
+
+    let lines = numbered(20, text)
+        .map(|line| {
+            let (from, tos) = line.split_once(" -> ")?;
+            let tos = tos
+                .split(", ")
+                .map(|to| parse_node(&line, to))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((parse_node(&line, from)?, tos))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    let mut index_to_node: Vec<Node> = vec![];
+    let mut node_to_index: HashMap<String, usize> = HashMap::<String, usize>::new();
+
+    fn set_node(
+        node: &Node,
+        node_to_index: &mut HashMap<String, usize>,
+        index_to_node: &mut Vec<Node>,
+    ) -> bool {
+        if !node_to_index.contains_key(&node.name) {
+            node_to_index.insert(node.name.clone(), index_to_node.len());
+            index_to_node.push(node.clone());
+            true
+        } else {
+            false
+        }
+    }
+
+    for (node, tos) in lines.iter() {
+        if !set_node(node, &mut node_to_index, &mut index_to_node) {
+            let index = *node_to_index.get(&node.name).unwrap();
+            let node_old = &mut index_to_node[index];
+            node_old.t = node.t.clone();
+        }
+
+        for to in tos {
+            let _ = set_node(to, &mut node_to_index, &mut index_to_node);
+        }
+    }
+
+    let mut edges: Vec<Vec<usize>> = vec![vec![]; index_to_node.len()];
+
+    for (from, tos) in lines.iter() {
+        let from_index = *node_to_index.get(&from.name).unwrap();
+
+        for to in tos.iter().map(|to| {
+            let to_index = *node_to_index.get(&to.name).unwrap();
+
+            let node = &mut index_to_node[to_index];
+            if let Type::Conjunction(ref mut v) = node.t {
+                v.push((from_index, false))
+            }
+
+            to_index
+        }) {
+            edges[from_index].push(to);
+        }
+    }
+
+    let broadcaster = *node_to_index
+        .get("broadcaster")
+        .ok_or_else(|| ParseError::missing(20, text, "broadcaster"))?;
+
+    Ok(PulseNetwork {
+        nodes: index_to_node,
+        index: node_to_index,
+        edges,
+        broadcaster,
+    })
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub enum Type {
+    None,
+    Start,
+    FlipFlop(bool),
+    Conjunction(Vec<(usize, bool)>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub t: Type,
+    pub name: String,
+}
+
+impl Node {
+    fn process(&mut self, signal: bool, from_ind: usize) -> Option<bool> {
+        match self.t {
+            Type::Start => Some(signal),
+            Type::FlipFlop(ref mut state) => {
+                if !signal {
+                    *state = !*state;
+                    Some(*state)
+                } else {
+                    None
+                }
+            }
+            Type::Conjunction(ref mut states) => {
+                if let Some(from) = states.iter_mut().find(|x| x.0 == from_ind) {
+                    *from = (from.0, signal);
+                }
+                Some(!states.iter().all(|x| x.1))
+            }
+            Type::None => None,
+        }
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl Eq for Node {}
+
+// One pulse on its way, `from` is `None` for the button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: Option<usize>,
+    pub to: usize,
+    pub high: bool,
+}
+
+// What a single press of the button set off
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Press {
+    pub low: u64,
+    pub high: u64,
+    // In the order they were sent
+    pub pulses: Vec<Pulse>,
+}
+
+// The state of every module, enough to put the network back the way it was
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot(Vec<Type>);
+
+// The state after `start` presses comes back after every further `length` presses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct PulseNetwork {
+    nodes: Vec<Node>,
+    index: NodeIndex,
+    edges: Vec<Vec<usize>>,
+    broadcaster: usize,
+}
+
+impl PulseNetwork {
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    // The modules every module sends its pulses to
+    pub fn edges(&self) -> &[Vec<usize>] {
+        &self.edges
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn press_button(&mut self) -> Press {
+        let mut press = Press {
+            low: 0,
+            high: 0,
+            pulses: vec![],
+        };
+        let mut q = VecDeque::from([Pulse {
+            from: None,
+            to: self.broadcaster,
+            high: false,
+        }]);
+
+        while let Some(pulse) = q.pop_front() {
+            if pulse.high {
+                press.high += 1;
+            } else {
+                press.low += 1;
+            }
+            press.pulses.push(pulse);
+
+            // The button only talks to the broadcaster, which does not care who it was
+            let from = pulse.from.unwrap_or(usize::MAX);
+            if let Some(high) = self.nodes[pulse.to].process(pulse.high, from) {
+                for &to in &self.edges[pulse.to] {
+                    q.push_back(Pulse {
+                        from: Some(pulse.to),
+                        to,
+                        high,
+                    });
+                }
+            }
+        }
+
+        press
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(self.nodes.iter().map(|node| node.t.clone()).collect())
+    }
+
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        for (node, t) in self.nodes.iter_mut().zip(&snapshot.0) {
+            node.t = t.clone();
+        }
+    }
+
+    // Presses the button until the whole state repeats, giving up after `limit` presses.
+    // Every press is handed to `on_press`, the network is left as it was.
+    pub fn find_cycle(&mut self, limit: usize, mut on_press: impl FnMut(Press)) -> Option<Cycle> {
+        let initial = self.snapshot();
+        let mut seen = HashMap::from([(initial.clone(), 0)]);
+        let mut cycle = None;
+
+        for presses in 1..=limit {
+            on_press(self.press_button());
+
+            if let Some(start) = seen.insert(self.snapshot(), presses) {
+                cycle = Some(Cycle {
+                    start,
+                    length: presses - start,
+                });
+                break;
+            }
+        }
+
+        self.restore(&initial);
+        cycle
+    }
+
+    // Flip-flops that are on and conjunctions that remember a high pulse from every input
+    fn is_on(node: &Node) -> bool {
+        match &node.t {
+            Type::FlipFlop(on) => *on,
+            Type::Conjunction(memory) => memory.iter().all(|(_, high)| *high),
+            _ => false,
+        }
+    }
+
+    // Graphviz with flip-flops as boxes and conjunctions as diamonds. With `colored` the modules
+    // that are on are filled in.
+    pub fn to_dot(&self, colored: bool) -> String {
+        let mut out = "digraph modules {\n".to_string();
+
+        for node in &self.nodes {
+            let shape = match node.t {
+                Type::Start => "doubleoctagon",
+                Type::FlipFlop(_) => "box",
+                Type::Conjunction(_) => "diamond",
+                Type::None => "ellipse",
+            };
+            let fill = if colored && Self::is_on(node) {
+                format!(r#", style=filled, fillcolor="{ON_COLOR}""#)
+            } else {
+                String::new()
+            };
+            writeln!(out, r#"  "{}" [shape={shape}{fill}];"#, node.name).unwrap();
+        }
+        for (from, tos) in self.edges.iter().enumerate() {
+            for &to in tos {
+                let (from, to) = (&self.nodes[from].name, &self.nodes[to].name);
+                writeln!(out, r#"  "{from}" -> "{to}";"#).unwrap();
+            }
+        }
+        out.push_str("}\n");
+
+        out
+    }
+
+    // The same as `to_dot` as a Mermaid flowchart. Nodes are numbered since some names,
+    // e.g. `end`, are keywords there.
+    pub fn to_mermaid(&self, colored: bool) -> String {
+        let mut out = "flowchart LR\n".to_string();
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let name = &node.name;
+            let shape = match node.t {
+                Type::Start => format!("([{name}])"),
+                Type::FlipFlop(_) => format!("[%{name}]"),
+                Type::Conjunction(_) => format!("{{&{name}}}"),
+                Type::None => format!("(({name}))"),
+            };
+            writeln!(out, "  n{i}{shape}").unwrap();
+        }
+        for (from, tos) in self.edges.iter().enumerate() {
+            for to in tos {
+                writeln!(out, "  n{from} --> n{to}").unwrap();
+            }
+        }
+        if colored {
+            for (i, node) in self.nodes.iter().enumerate() {
+                if Self::is_on(node) {
+                    writeln!(out, "  style n{i} fill:{ON_COLOR}").unwrap();
+                }
+            }
+        }
+
+        out
+    }
+}
+
+// Fill of the modules that are on in the drawings
+const ON_COLOR: &str = "#8fd18f";
+
+// How long part 2 waits for the inputs of the hub to repeat
+const MAX_PRESSES: usize = 1 << 20;
+
+// `rx`, the conjunction that is its only input and the inputs of that conjunction
+fn rx_hub(network: &PulseNetwork) -> Res<(usize, usize, Vec<usize>)> {
+    let rx = network.index("rx").ok_or("There is no module `rx`")?;
+
+    let feeders = (0..network.nodes().len())
+        .filter(|from| network.edges()[*from].contains(&rx))
+        .collect::<Vec<_>>();
+    let [hub] = feeders[..] else {
+        return Err(format!("`rx` should have a single input, not {}", feeders.len()).into());
+    };
+
+    let Type::Conjunction(memory) = &network.nodes()[hub].t else {
+        return Err(format!(
+            "`{}` feeding `rx` should be a conjunction",
+            network.nodes()[hub].name
+        )
+        .into());
+    };
+
+    if memory.is_empty() {
+        return Err(format!(
+            "`{}` feeding `rx` has no inputs, so `rx` is never reached",
+            network.nodes()[hub].name
+        )
+        .into());
+    }
+
+    Ok((rx, hub, memory.iter().map(|(from, _)| *from).collect()))
+}
+
+pub struct Day20 {
+    // Where to write the network as Graphviz and as a Mermaid flowchart
+    pub dot: Option<String>,
+    pub mermaid: Option<String>,
+    // Color the drawings by the state of the modules after this many presses
+    pub presses: Option<usize>,
+}
+
+impl Day20 {
+    pub const PUZZLE: Day20 = Day20 {
+        dot: None,
+        mermaid: None,
+        presses: None,
+    };
+
+    fn export(&self, network: &PulseNetwork) -> Res<()> {
+        let mut network = network.clone();
+        for _ in 0..self.presses.unwrap_or(0) {
+            network.press_button();
+        }
+        let colored = self.presses.is_some();
+
+        if let Some(path) = &self.dot {
+            fs::write(path, network.to_dot(colored)).map_err(|err| format!("{path}: {err}"))?;
+        }
+        if let Some(path) = &self.mermaid {
+            fs::write(path, network.to_mermaid(colored)).map_err(|err| format!("{path}: {err}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for Day20 {
+    type Input = PulseNetwork;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        const PRESSES: usize = 1000;
+
+        // The drawings do not depend on the part, part 1 writes them
+        self.export(input)?;
+
+        let mut network = input.clone();
+
+        // Only the presses up to the end of the first cycle are different
+        let mut counts = vec![];
+        let cycle = network.find_cycle(PRESSES, |press| counts.push([press.low, press.high]));
+        let pressed = counts.len();
+
+        let pulses = (0..PRESSES).fold([0, 0], |[low, high], press| {
+            let press = match cycle {
+                Some(cycle) if press >= pressed => {
+                    cycle.start + (press - cycle.start) % cycle.length
+                }
+                _ => press,
+            };
+            [low + counts[press][0], high + counts[press][1]]
+        });
+
+        Ok(pulses[0] * pulses[1])
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let mut network = input.clone();
+
+        let (rx, hub, inputs) = rx_hub(&network)?;
+        // The presses every input of the hub sent it a high pulse at, the first two are enough
+        let mut highs = vec![vec![]; inputs.len()];
+
+        for pressed in 1..=MAX_PRESSES {
+            for pulse in network.press_button().pulses {
+                // Small networks get there on their own
+                if pulse.to == rx && !pulse.high {
+                    return Ok(pressed as u64);
+                }
+
+                if pulse.to == hub && pulse.high {
+                    if let Some(i) = inputs.iter().position(|x| Some(*x) == pulse.from) {
+                        if highs[i].len() < 2 && highs[i].last() != Some(&pressed) {
+                            highs[i].push(pressed);
+                        }
+                    }
+                }
+            }
+
+            if highs.iter().all(|presses| presses.len() == 2) {
+                break;
+            }
+        }
+
+        // The hub sends a low pulse once all of its inputs sent a high one during the same press,
+        // which only works out as an LCM when every input does so every `period` presses
+        let periods = inputs
+            .iter()
+            .zip(highs)
+            .map(|(&input, presses)| {
+                let name = &network.nodes()[input].name;
+                match presses[..] {
+                    [first, second] if second - first == first => Ok(first as u64),
+                    [first, second] => Err(format!(
+                        "`{name}` sends high pulses at presses {first} and {second}, not every {first} presses"
+                    )),
+                    _ => Err(format!(
+                        "`{name}` did not send two high pulses in {MAX_PRESSES} presses"
+                    )),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(periods.into_iter().fold(1, lcm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, Cycle, Day20};
+    use crate::solution::Solution;
+
+    const LOOP: &str = "broadcaster -> a\n\
+                        %a -> inv, con\n\
+                        &inv -> b\n\
+                        %b -> con\n\
+                        &con -> output";
+
+    #[test]
+    fn pulse_network_works() {
+        let mut network = parse_input(LOOP).unwrap();
+        let initial = network.snapshot();
+
+        let press = network.press_button();
+        assert_eq!((press.low, press.high), (4, 4));
+        assert_eq!(press.pulses.len(), 8);
+        assert_eq!(press.pulses[0].from, None);
+        assert_ne!(network.snapshot(), initial);
+
+        // The flip-flops are back where they were after 4 presses, from any state
+        let pressed = network.snapshot();
+        assert_eq!(
+            network.find_cycle(100, drop),
+            Some(Cycle {
+                start: 0,
+                length: 4
+            })
+        );
+        assert_eq!(network.snapshot(), pressed);
+
+        network.restore(&initial);
+        assert_eq!(network.snapshot(), initial);
+        assert_eq!(network.find_cycle(3, drop), None);
+
+        assert_eq!(Day20::PUZZLE.part1(&network).unwrap(), 11687500);
+    }
+
+    #[test]
+    fn drawings_work() {
+        let mut network = parse_input(LOOP).unwrap();
+        network.press_button();
+
+        let dot = network.to_dot(true);
+        assert!(dot.starts_with("digraph modules {\n"));
+        assert!(dot.contains(r#"  "broadcaster" [shape=doubleoctagon];"#));
+        assert!(dot.contains(r##"  "a" [shape=box, style=filled, fillcolor="#8fd18f"];"##));
+        // Both inputs of `con` were high last
+        assert!(dot.contains(r##"  "con" [shape=diamond, style=filled, fillcolor="#8fd18f"];"##));
+        assert!(dot.contains(r#"  "output" [shape=ellipse];"#));
+        assert!(dot.contains(r#"  "a" -> "con";"#));
+        assert!(!network.to_dot(false).contains("filled"));
+
+        let mermaid = network.to_mermaid(true);
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("  n1[%a]\n"));
+        assert!(mermaid.contains("{&con}"));
+        assert!(mermaid.contains("((output))"));
+        assert_eq!(mermaid.matches(" --> ").count(), 6);
+        assert_eq!(mermaid.matches("  style ").count(), 4);
+    }
+
+    #[test]
+    fn rx_hub_works() {
+        // Two flip-flop counters, inverted into the hub every 4 and every 8 presses
+        let network = parse_input(
+            "broadcaster -> a1, b1\n\
+             %a1 -> a2\n\
+             %a2 -> ia\n\
+             &ia -> hub\n\
+             %b1 -> b2\n\
+             %b2 -> b3\n\
+             %b3 -> ib\n\
+             &ib -> hub\n\
+             &hub -> rx",
+        )
+        .unwrap();
+        assert_eq!(Day20::PUZZLE.part2(&network).unwrap(), 8);
+
+        for (text, err) in [
+            ("broadcaster -> a\n%a -> b", "There is no module `rx`"),
+            (
+                "broadcaster -> a, b\n%a -> rx\n%b -> rx",
+                "`rx` should have a single input, not 2",
+            ),
+            (
+                "broadcaster -> a\n%a -> rx",
+                "`a` feeding `rx` should be a conjunction",
+            ),
+            (
+                "broadcaster -> a\n%a -> b\n&hub -> rx",
+                "`hub` feeding `rx` has no inputs, so `rx` is never reached",
+            ),
+        ] {
+            let network = parse_input(text).unwrap();
+            assert_eq!(Day20::PUZZLE.part2(&network).unwrap_err().to_string(), err);
+        }
+    }
+}
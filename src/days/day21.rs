@@ -0,0 +1,198 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    direction::Direction,
+    graph::bfs,
+    grid::{Grid, Point},
+    input::{ParseError, Res},
+    math::lcm,
+    solution::Solution,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Type {
+    Start,
+    Plot,
+    Rock,
+}
+
+fn parse_input(text: &str) -> Res<(Point, Grid<Type>)> {
+    let lines = Grid::parse(21, text, |c| match c {
+        '.' => Some(Type::Plot),
+        'S' => Some(Type::Start),
+        '#' => Some(Type::Rock),
+        _ => None,
+    })?;
+
+    let start = lines
+        .position(|c| c == &Type::Start)
+        .ok_or_else(|| ParseError::missing(21, text, "start `S`"))?;
+
+    Ok((start, lines))
+}
+
+// Number of plots reachable in exactly `steps` steps on the infinitely repeated map
+fn reachable_infinite(start: Point, mtx: &Grid<Type>, steps: usize) -> usize {
+    let n = mtx.rows() as i32;
+    let m = mtx.cols() as i32;
+    // The map repeats every `n` rows and every `m` columns, so every `lcm` steps in both.
+    // After an odd period the reachable plots are the other color of the checkerboard.
+    let period = lcm(n, m) as usize;
+    let period = if period % 2 == 1 { 2 * period } else { period };
+
+    let mut visited = HashSet::<Point>::new();
+    let mut q = VecDeque::<Point>::new();
+    // Reachable cells with even and odd distance seen so far
+    let mut cnt = [0, 0];
+    // Reachable count at every `steps % period + k * period` step
+    let mut samples: Vec<i128> = vec![];
+
+    visited.insert(start);
+    q.push_back(start);
+
+    for step in 0..=steps {
+        cnt[step % 2] += q.len();
+
+        if step % period == steps % period {
+            samples.push(cnt[step % 2] as i128);
+
+            // The count grows quadratically once the frontier has passed a few copies of the map.
+            // Wait until the second difference settles and extrapolate from there.
+            let k = samples.len();
+            if k >= 5 {
+                let d2 = |i: usize| samples[i] + samples[i - 2] - 2 * samples[i - 1];
+                if d2(k - 1) == d2(k - 2) && d2(k - 2) == d2(k - 3) {
+                    let (a, b, c) = (samples[k - 3], samples[k - 2], samples[k - 1]);
+                    let left = ((steps - step) / period) as i128;
+
+                    // Newton forward differences from the last sample
+                    let d1 = c - b;
+                    let d2 = c + a - 2 * b;
+
+                    return (c + left * d1 + left * (left + 1) / 2 * d2) as usize;
+                }
+            }
+        }
+
+        if step == steps {
+            break;
+        }
+
+        for _ in 0..q.len() {
+            let cur = q.pop_front().unwrap();
+            for dir in Direction::ALL {
+                let to = dir.step(cur);
+                let cell = &mtx[(to.0.rem_euclid(n), to.1.rem_euclid(m))];
+                if cell != &Type::Rock && visited.insert(to) {
+                    q.push_back(to);
+                }
+            }
+        }
+    }
+
+    cnt[steps % 2]
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (Point, Grid<Type>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (start, mtx) = input;
+
+        let dist = bfs(*start, 64, |&cur| {
+            Direction::ALL
+                .iter()
+                .map(move |dir| dir.step(cur))
+                .filter(|to| mtx.get(*to).is_some_and(|cell| cell != &Type::Rock))
+                .collect::<Vec<_>>()
+        });
+
+        // Any plot reached earlier with the same parity can be revisited by stepping back and forth
+        Ok(dist.values().filter(|d| *d % 2 == 0).count())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (start, mtx) = input;
+
+        Ok(reachable_infinite(*start, mtx, 26501365))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{parse_input, reachable_infinite, Type};
+    use crate::{
+        direction::Direction,
+        grid::{Grid, Point},
+        input::read_input,
+    };
+
+    const IN_SMALL: &str = "assets/day21/in_small.txt";
+    const IN: &str = "assets/day21/in.txt";
+
+    fn reachable_brute(start: Point, mtx: &Grid<Type>, steps: usize) -> usize {
+        let n = mtx.rows() as i32;
+        let m = mtx.cols() as i32;
+
+        let mut hm = HashSet::<Point>::new();
+        hm.insert(start);
+        for _ in 0..steps {
+            hm = hm
+                .iter()
+                .flat_map(|cur| Direction::ALL.iter().map(move |dir| dir.step(*cur)))
+                .filter(|to| mtx[(to.0.rem_euclid(n), to.1.rem_euclid(m))] != Type::Rock)
+                .collect();
+        }
+
+        hm.len()
+    }
+
+    #[test]
+    fn reachable_infinite_small_works() {
+        let (start, mtx) = parse_input(&read_input(IN_SMALL).unwrap()).unwrap();
+
+        for (steps, expected) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(reachable_infinite(start, &mtx, steps), expected);
+        }
+    }
+
+    #[test]
+    fn reachable_infinite_matches_brute_force() {
+        for (file, steps) in [
+            (IN_SMALL, [0, 1, 7, 33, 60, 111]),
+            (IN, [0, 1, 7, 64, 65, 131]),
+        ] {
+            let (start, mtx) = parse_input(&read_input(file).unwrap()).unwrap();
+
+            for steps in steps {
+                assert_eq!(
+                    reachable_infinite(start, &mtx, steps),
+                    reachable_brute(start, &mtx, steps)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reachable_infinite_non_square_works() {
+        // Repeats every 3 rows and 5 columns, sampled every 30 steps
+        let (start, mtx) = parse_input(".#...\n..S..\n...#.").unwrap();
+
+        for steps in [160, 175] {
+            assert_eq!(
+                reachable_infinite(start, &mtx, steps),
+                reachable_brute(start, &mtx, steps)
+            );
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    solution::Solution,
+};
+
+pub type Point = (usize, usize, usize);
+
+#[derive(Debug, Clone)]
+pub struct Cube(Point, Point, usize);
+
+impl Cube {
+    #[allow(clippy::needless_range_loop)]
+    fn fall(&mut self, field: &mut [Vec<Vec<i32>>], mxs: &mut [Vec<usize>]) {
+        let lower = self.0 .2;
+        let upper = self.1 .2;
+        let heigh = upper - lower + 1;
+
+        let mut mx = 0;
+        for x in self.0 .0..=self.1 .0 {
+            for y in self.0 .1..=self.1 .1 {
+                mx = mx.max(mxs[x][y]);
+            }
+        }
+        assert!(lower > mx);
+        let down = lower - mx - 1;
+
+        for x in self.0 .0..=self.1 .0 {
+            for y in self.0 .1..=self.1 .1 {
+                mxs[x][y] = mx + heigh;
+            }
+        }
+
+        self.0 .2 -= down;
+        self.1 .2 -= down;
+
+        for x in self.0 .0..=self.1 .0 {
+            for y in self.0 .1..=self.1 .1 {
+                for z in self.0 .2..=self.1 .2 {
+                    field[x][y][z] = self.2 as i32;
+                }
+            }
+        }
+    }
+}
+
+fn parse_input(text: &str) -> Res<Vec<Cube>> {
+    fn parse_point(line: &Line, str: &str) -> Result<Point, ParseError> {
+        let points = str
+            .split(',')
+            .map(|y| line.parse::<usize>(y, "coordinate"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match points[..] {
+            [x, y, z] if z > 0 => Ok((x, y, z)),
+            [_, _, _] => Err(line.error(str, "bricks must be above the ground")),
+            _ => Err(line.error(str, "expected `<x>,<y>,<z>`")),
+        }
+    }
+
+    let cubes = numbered(22, text)
+        .enumerate()
+        .map(|(ind, line)| {
+            let (first, second) = line.split_once("~")?;
+            let first = parse_point(&line, first)?;
+            let second = parse_point(&line, second)?;
+
+            if first.0 > second.0 || first.1 > second.1 || first.2 > second.2 {
+                return Err(line.error(line.text, "brick ends are not ordered"));
+            }
+
+            Ok(Cube(first, second, ind))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok(cubes)
+}
+
+type Relation = Vec<HashSet<usize>>;
+
+#[allow(clippy::needless_range_loop)]
+fn solve(cubes: &[Cube]) -> (Vec<bool>, Relation, Relation) {
+    let mut cubes = cubes.to_vec();
+
+    cubes.sort_by(
+        |Cube(a_f, a_s, _), Cube(b_f, b_s, _)| match a_f.2.cmp(&b_f.2) {
+            std::cmp::Ordering::Equal => a_s.2.cmp(&b_s.2),
+            x => x,
+        },
+    );
+
+    let mut max_dim = 0;
+    for cube in cubes.iter_mut() {
+        max_dim = max_dim.max(cube.1 .0);
+        max_dim = max_dim.max(cube.1 .1);
+        max_dim = max_dim.max(cube.1 .2);
+    }
+
+    let mut field = vec![vec![vec![-1; max_dim]; max_dim]; max_dim];
+    let mut mxs = vec![vec![0; max_dim]; max_dim];
+
+    for cube in cubes.iter_mut() {
+        cube.fall(&mut field, &mut mxs);
+    }
+
+    let mut can_not_be_removed: Vec<bool> = vec![true; cubes.len()];
+    let mut support_for: Relation = vec![HashSet::new(); cubes.len()];
+    let mut lays_on: Relation = vec![HashSet::new(); cubes.len()];
+
+    for cube in cubes.iter().rev() {
+        let bottom = cube.0 .2;
+
+        for x in cube.0 .0..=cube.1 .0 {
+            for y in cube.0 .1..=cube.1 .1 {
+                if field[x][y][bottom - 1] != -1 {
+                    lays_on[cube.2].insert(field[x][y][bottom - 1] as usize);
+                }
+            }
+        }
+
+        for x in &lays_on[cube.2] {
+            support_for[*x].insert(cube.2);
+        }
+
+        if lays_on[cube.2].len() == 1 {
+            can_not_be_removed[*lays_on[cube.2].iter().next().unwrap() as usize] = false;
+        }
+    }
+
+    (can_not_be_removed, support_for, lays_on)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (can_not_be_removed, _, _) = solve(input);
+
+        Ok(can_not_be_removed.into_iter().filter(|x| *x).count())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (can_not_be_removed, support_for, lays_on) = solve(input);
+
+        let ans = can_not_be_removed
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, x)| (!x).then_some(i))
+            .map(|i| {
+                let mut falls = HashSet::<usize>::new();
+                let mut q = VecDeque::new();
+                q.push_back(i);
+
+                while let Some(x) = q.pop_front() {
+                    falls.insert(x);
+
+                    for lays_on_current in &support_for[x] {
+                        if falls
+                            .intersection(&lays_on[*lays_on_current])
+                            .collect::<HashSet<_>>()
+                            .len()
+                            == lays_on[*lays_on_current].len()
+                        {
+                            falls.insert(*lays_on_current);
+                            q.push_back(*lays_on_current);
+                        };
+                    }
+                }
+
+                falls.len() - 1
+            })
+            .sum::<usize>();
+
+        Ok(ans)
+    }
+}
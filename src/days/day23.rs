@@ -0,0 +1,274 @@
+use std::{fmt::Write, fs};
+
+use crate::{
+    direction::Direction,
+    grid::{Grid, Point},
+    input::{part_path, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Grid<Type>> {
+    let mtx = Grid::parse(23, text, |c| match c {
+        '#' => Some(Type::Forest),
+        '.' => Some(Type::Path),
+        _ => Direction::from_arrow(c).map(Type::Slope),
+    })?;
+
+    Ok(mtx)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Type {
+    Forest,
+    Path,
+    Slope(Direction),
+}
+
+// The trails squeezed down to the tiles where there is a choice to make
+#[derive(Debug)]
+pub struct JunctionGraph {
+    // Position of every node: the entrance, the exit and every junction
+    pub nodes: Vec<Point>,
+    // Corridors leaving every node as the node they lead to and their length
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl JunctionGraph {
+    // With `slopes` a corridor can only be walked down its slopes, so some edges go one way only
+    pub fn new(mtx: &Grid<Type>, slopes: bool) -> Res<Self> {
+        let open = |point: Point| mtx.get(point).is_some_and(|t| t != &Type::Forest);
+        let gap = |i: usize| {
+            (0..mtx.cols())
+                .map(|j| (i as i32, j as i32))
+                .find(|point| open(*point))
+        };
+
+        let start = gap(0).ok_or("There is no entrance in the top row")?;
+        let end = gap(mtx.rows() - 1).ok_or("There is no exit in the bottom row")?;
+
+        let mut nodes = vec![start, end];
+        nodes.extend(mtx.points().filter(|point| {
+            open(*point)
+                && Direction::ALL
+                    .iter()
+                    .filter(|dir| open(dir.step(*point)))
+                    .count()
+                    >= 3
+        }));
+
+        // The ways out of a tile, a slope only lets you go down it
+        let moves = |point: Point| match &mtx[point] {
+            Type::Slope(dir) if slopes => vec![*dir],
+            _ => Direction::ALL.to_vec(),
+        };
+
+        let edges = nodes
+            .iter()
+            .map(|&node| {
+                moves(node)
+                    .into_iter()
+                    .filter_map(|first| {
+                        let (mut prev, mut cur) = (node, first.step(node));
+                        let mut length = 1;
+
+                        // Corridors have a single way on until the next node
+                        loop {
+                            if !open(cur) {
+                                return None;
+                            }
+                            if let Some(to) = nodes.iter().position(|node| node == &cur) {
+                                return Some((to, length));
+                            }
+
+                            let next = moves(cur)
+                                .into_iter()
+                                .map(|dir| dir.step(cur))
+                                .find(|next| next != &prev && open(*next))?;
+                            (prev, cur) = (cur, next);
+                            length += 1;
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(JunctionGraph {
+            nodes,
+            edges,
+            start: 0,
+            end: 1,
+        })
+    }
+
+    // Length of the longest walk from the entrance to the exit without stepping on a tile twice
+    pub fn longest_path(&self) -> Res<Option<usize>> {
+        if self.nodes.len() > u64::BITS as usize {
+            return Err(
+                format!("{} junctions do not fit into a u64 mask", self.nodes.len()).into(),
+            );
+        }
+
+        // The longest corridor into every node: a walk enters every node it visits at most once
+        let mut bound = vec![0; self.nodes.len()];
+        for (to, length) in self.edges.iter().flatten() {
+            bound[*to] = bound[*to].max(*length);
+        }
+
+        // When a single node leads to the exit, leaving it any other way locks the exit out
+        let into_end = (0..self.nodes.len())
+            .filter_map(|from| {
+                let (_, length) = self.edges[from].iter().find(|(to, _)| *to == self.end)?;
+                Some((from, *length))
+            })
+            .collect::<Vec<_>>();
+
+        let mut search = Search {
+            graph: self,
+            bound,
+            last: (into_end.len() == 1).then(|| into_end[0]),
+            best: None,
+        };
+        let remaining = search.bound.iter().sum::<usize>() - search.bound[self.start];
+        search.dfs(self.start, 1 << self.start, 0, remaining);
+
+        Ok(search.best)
+    }
+
+    // Graphviz with the junctions as `row,column` and the corridor lengths on the edges.
+    // An undirected graph has every corridor twice in `edges`, it is drawn once.
+    pub fn to_dot(&self, directed: bool) -> String {
+        let (kind, arrow) = if directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut out = format!("{kind} trails {{\n");
+        for (node, (i, j)) in self.nodes.iter().enumerate() {
+            let shape = if node == self.start || node == self.end {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            writeln!(out, r#"  {node} [label="{i},{j}", shape={shape}];"#).unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, length) in edges {
+                if directed || from < *to {
+                    writeln!(out, r#"  {from} {arrow} {to} [label="{length}"];"#).unwrap();
+                }
+            }
+        }
+        out.push_str("}\n");
+
+        out
+    }
+}
+
+struct Search<'a> {
+    graph: &'a JunctionGraph,
+    bound: Vec<usize>,
+    // The only node next to the exit and the length of its corridor there
+    last: Option<(usize, usize)>,
+    best: Option<usize>,
+}
+
+impl Search<'_> {
+    // `remaining` is the sum of `bound` over the nodes not in `visited`
+    fn dfs(&mut self, cur: usize, visited: u64, length: usize, remaining: usize) {
+        if cur == self.graph.end {
+            self.best = self.best.max(Some(length));
+            return;
+        }
+        if let Some((last, to_end)) = self.last {
+            if cur == last {
+                self.best = self.best.max(Some(length + to_end));
+                return;
+            }
+        }
+        if self.best.is_some_and(|best| length + remaining <= best) {
+            return;
+        }
+
+        for &(to, corridor) in &self.graph.edges[cur] {
+            if visited & (1 << to) == 0 {
+                self.dfs(
+                    to,
+                    visited | (1 << to),
+                    length + corridor,
+                    remaining - self.bound[to],
+                );
+            }
+        }
+    }
+}
+
+pub struct Day23 {
+    // Where to write the junction graph of each part as Graphviz, see `part_path`
+    pub dot: Option<String>,
+}
+
+impl Day23 {
+    // Part 1 has to go down the slopes, part 2 can walk them both ways
+    fn solve(&self, mtx: &Grid<Type>, part: usize) -> Res<usize> {
+        let slopes = part == 1;
+        let graph = JunctionGraph::new(mtx, slopes)?;
+
+        if let Some(path) = &self.dot {
+            let path = part_path(path, part);
+            fs::write(&path, graph.to_dot(slopes)).map_err(|err| format!("{path}: {err}"))?;
+        }
+
+        Ok(graph.longest_path()?.ok_or("There is no way to the exit")?)
+    }
+}
+
+impl Solution for Day23 {
+    type Input = Grid<Type>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        self.solve(input, 1)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        self.solve(input, 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_input, JunctionGraph};
+    use crate::input::read_input;
+
+    #[test]
+    fn junction_graph_works() {
+        let mtx = parse_input(&read_input("assets/day23/in_small.txt").unwrap()).unwrap();
+
+        let directed = JunctionGraph::new(&mtx, true).unwrap();
+        let undirected = JunctionGraph::new(&mtx, false).unwrap();
+
+        // Entrance, exit and seven junctions
+        assert_eq!(directed.nodes.len(), 9);
+        assert_eq!(directed.nodes[..2], [(0, 1), (22, 21)]);
+        // Every corridor of the example has a slope, so it is walked one way or both ways
+        assert_eq!(directed.edges.iter().flatten().count(), 12);
+        assert_eq!(undirected.edges.iter().flatten().count(), 24);
+
+        assert_eq!(directed.longest_path().unwrap(), Some(94));
+        assert_eq!(undirected.longest_path().unwrap(), Some(154));
+
+        let dot = directed.to_dot(true);
+        assert!(dot.starts_with("digraph trails {\n"));
+        assert!(dot.contains(r#"  0 [label="0,1", shape=doublecircle];"#));
+        assert_eq!(dot.matches(" -> ").count(), 12);
+        assert_eq!(undirected.to_dot(false).matches(" -- ").count(), 12);
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    solution::Solution,
+};
+
+mod cm {
+    pub fn gaussian_elimination(matrix: &mut [Vec<f64>]) -> Option<Vec<f64>> {
+        let size = matrix.len();
+        assert_eq!(size, matrix[0].len() - 1);
+
+        for i in 0..size - 1 {
+            for j in i..size - 1 {
+                echelon(matrix, i, j);
+            }
+        }
+
+        for i in (1..size).rev() {
+            eliminate(matrix, i);
+        }
+
+        let mut inf = false;
+        #[allow(clippy::needless_range_loop)]
+        for i in 0..size {
+            if matrix[i][i] == 0f64 {
+                println!("Infinitely many solutions");
+                inf = true;
+            }
+        }
+
+        if inf {
+            return None;
+        }
+
+        let mut result: Vec<f64> = vec![0f64; size];
+        for i in 0..size {
+            result[i] = matrix[i][size] / matrix[i][i];
+        }
+        Some(result)
+    }
+
+    fn echelon(matrix: &mut [Vec<f64>], i: usize, j: usize) {
+        let size = matrix.len();
+        if matrix[i][i] == 0f64 {
+        } else {
+            let factor = matrix[j + 1][i] / matrix[i][i];
+            (i..size + 1).for_each(|k| {
+                matrix[j + 1][k] -= factor * matrix[i][k];
+            });
+        }
+    }
+
+    fn eliminate(matrix: &mut [Vec<f64>], i: usize) {
+        let size = matrix.len();
+        if matrix[i][i] == 0f64 {
+        } else {
+            for j in (1..i + 1).rev() {
+                let factor = matrix[j - 1][i] / matrix[i][i];
+                for k in (0..size + 1).rev() {
+                    matrix[j - 1][k] -= factor * matrix[i][k];
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::gaussian_elimination;
+
+        #[test]
+        fn test_gauss() {
+            let mut matrix: Vec<Vec<f64>> = vec![
+                vec![1.0, 2.0, 3.0, 0.0],
+                vec![3.0, 4.0, 7.0, 2.0],
+                vec![6.0, 5.0, 9.0, 11.0],
+            ];
+            let result = vec![4.0, 1.0, -2.0];
+            assert_eq!(gaussian_elimination(&mut matrix).unwrap(), result);
+        }
+    }
+}
+
+pub type Point = (i64, i64, i64);
+
+#[derive(Debug)]
+pub struct Ray {
+    pub position: Point,
+    pub direction: Point,
+}
+
+// The rock is printed as the sum of its starting coordinates
+#[derive(Debug)]
+pub struct Throw {
+    pub sum: i64,
+    pub rock: Ray,
+}
+
+impl std::fmt::Display for Throw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.sum)
+    }
+}
+
+fn parse_input(text: &str) -> Res<Vec<Ray>> {
+    fn parse_point(line: &Line, str: &str) -> Result<Point, ParseError> {
+        let values = str
+            .split(',')
+            .map(|x| line.parse::<i64>(x.trim(), "number"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values[..] {
+            [x, y, z] => Ok((x, y, z)),
+            _ => Err(line.error(str.trim(), "expected `<x>, <y>, <z>`")),
+        }
+    }
+
+    Ok(numbered(24, text)
+        .map(|line| {
+            let (position, direction) = line.split_once("@")?;
+
+            Ok(Ray {
+                position: parse_point(&line, position)?,
+                direction: parse_point(&line, direction)?,
+            })
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?)
+}
+
+mod exact {
+    use crate::math::gcd;
+
+    pub type Vector = (i128, i128, i128);
+
+    pub fn sub(a: Vector, b: Vector) -> Vector {
+        (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+    }
+
+    pub fn add(a: Vector, b: Vector) -> Vector {
+        (a.0 + b.0, a.1 + b.1, a.2 + b.2)
+    }
+
+    pub fn mul(a: Vector, k: i128) -> Vector {
+        (a.0 * k, a.1 * k, a.2 * k)
+    }
+
+    pub fn dot(a: Vector, b: Vector) -> i128 {
+        a.0 * b.0 + a.1 * b.1 + a.2 * b.2
+    }
+
+    pub fn cross(a: Vector, b: Vector) -> Vector {
+        (
+            a.1 * b.2 - a.2 * b.1,
+            a.2 * b.0 - a.0 * b.2,
+            a.0 * b.1 - a.1 * b.0,
+        )
+    }
+
+    // Same direction, smaller numbers
+    pub fn reduce(a: Vector) -> Vector {
+        let g = gcd(gcd(a.0, a.1), a.2).abs();
+        if g == 0 {
+            a
+        } else {
+            (a.0 / g, a.1 / g, a.2 / g)
+        }
+    }
+}
+
+fn widen(point: Point) -> exact::Vector {
+    (point.0 as i128, point.1 as i128, point.2 as i128)
+}
+
+// Time at which `ray` crosses the plane through the origin with the `normal`,
+// everything in the frame of the base hailstone. `None` if it never does at an integer time.
+fn hit_time(ray: &Ray, base: &Ray, normal: exact::Vector) -> Option<i128> {
+    let position = exact::sub(widen(ray.position), widen(base.position));
+    let direction = exact::sub(widen(ray.direction), widen(base.direction));
+
+    let num = -exact::dot(position, normal);
+    let den = exact::dot(direction, normal);
+
+    (den != 0 && num % den == 0).then(|| num / den)
+}
+
+fn throw_rock(rays: &[Ray]) -> Option<(exact::Vector, exact::Vector)> {
+    let n = rays.len();
+
+    // Looking from the `base` hailstone the rock passes through the origin,
+    // so it lies in the plane spanned by the origin and the `plane` hailstone's trajectory.
+    // The other two hailstones hit that plane exactly when the rock hits them.
+    for base in 0..n {
+        for plane in (0..n).filter(|&i| i != base) {
+            let position = exact::sub(widen(rays[plane].position), widen(rays[base].position));
+            let direction = exact::sub(widen(rays[plane].direction), widen(rays[base].direction));
+            let normal = exact::reduce(exact::cross(position, direction));
+            if normal == (0, 0, 0) {
+                continue;
+            }
+
+            let hits = (0..n)
+                .filter(|&i| i != base && i != plane)
+                .filter_map(|i| Some((i, hit_time(&rays[i], &rays[base], normal)?)))
+                .take(2)
+                .collect::<Vec<_>>();
+
+            let &[(a, t_a), (b, t_b)] = hits.as_slice() else {
+                continue;
+            };
+            if t_a == t_b {
+                continue;
+            }
+
+            let at = |i: usize, t: i128| {
+                exact::add(
+                    widen(rays[i].position),
+                    exact::mul(widen(rays[i].direction), t),
+                )
+            };
+            let (hit_a, hit_b) = (at(a, t_a), at(b, t_b));
+
+            let delta = exact::sub(hit_b, hit_a);
+            let dt = t_b - t_a;
+            if delta.0 % dt != 0 || delta.1 % dt != 0 || delta.2 % dt != 0 {
+                continue;
+            }
+
+            let velocity = (delta.0 / dt, delta.1 / dt, delta.2 / dt);
+            let position = exact::sub(hit_a, exact::mul(velocity, t_a));
+
+            // The rock and every hailstone have to meet at some non-negative time
+            let hits_all = rays.iter().all(|ray| {
+                let dp = exact::sub(position, widen(ray.position));
+                let dv = exact::sub(velocity, widen(ray.direction));
+                dv != (0, 0, 0) && exact::cross(dp, dv) == (0, 0, 0) && exact::dot(dp, dv) <= 0
+            });
+
+            if hits_all {
+                return Some((position, velocity));
+            }
+        }
+    }
+
+    None
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Ray>;
+    type Answer1 = u32;
+    type Answer2 = Throw;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let rays = input;
+
+        let n = rays.len();
+        let low = 200000000000000.0;
+        let high = 400000000000000.0;
+
+        let mut ans = 0;
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let mut matrix = vec![
+                    vec![
+                        rays[i].direction.0 as f64,
+                        -rays[j].direction.0 as f64,
+                        (rays[j].position.0 - rays[i].position.0) as f64,
+                    ],
+                    vec![
+                        rays[i].direction.1 as f64,
+                        -rays[j].direction.1 as f64,
+                        (rays[j].position.1 - rays[i].position.1) as f64,
+                    ],
+                ];
+                let xy = cm::gaussian_elimination(&mut matrix);
+
+                if let Some(xy) = xy {
+                    if xy.iter().all(|x| !x.is_nan() && x > &0.0) {
+                        let x = rays[i].position.0 as f64 + rays[i].direction.0 as f64 * xy[0];
+                        let y = rays[i].position.1 as f64 + rays[i].direction.1 as f64 * xy[0];
+
+                        if x >= low && x <= high && y >= low && y <= high {
+                            ans += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(ans)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let rays = input;
+
+        let (position, velocity) = throw_rock(rays).ok_or("No rock throw hits every hailstone")?;
+        let rock = Ray {
+            position: (position.0 as i64, position.1 as i64, position.2 as i64),
+            direction: (velocity.0 as i64, velocity.1 as i64, velocity.2 as i64),
+        };
+
+        Ok(Throw {
+            sum: rock.position.0 + rock.position.1 + rock.position.2,
+            rock,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day24, Throw};
+    use crate::solution::Solution;
+
+    const EXAMPLE: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+
+    #[test]
+    fn throw_rock_works() {
+        let rays = Day24.parse(EXAMPLE).unwrap();
+        let Throw { sum, rock } = Day24.part2(&rays).unwrap();
+
+        assert_eq!(sum, 47);
+        assert_eq!(rock.position, (24, 13, 10));
+        assert_eq!(rock.direction, (-3, 1, 2));
+    }
+}
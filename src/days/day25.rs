@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::{
+    graph::min_cut,
+    input::{numbered, Res},
+    solution::Solution,
+};
+
+pub type Graph = Vec<Vec<usize>>;
+
+fn parse_input(text: &str) -> Res<Graph> {
+    let mut name_to_index = HashMap::<String, usize>::new();
+    let mut graph: Graph = vec![];
+
+    let mut index = |name: &str, graph: &mut Graph| -> usize {
+        *name_to_index.entry(name.to_owned()).or_insert_with(|| {
+            graph.push(vec![]);
+            graph.len() - 1
+        })
+    };
+
+    for line in numbered(25, text) {
+        let (from, tos) = line.split_once(":")?;
+        let from = from.trim();
+        if from.is_empty() {
+            return Err(line.error(from, "missing component name").into());
+        }
+
+        let from = index(from, &mut graph);
+        for to in tos.split_whitespace() {
+            let to = index(to, &mut graph);
+            graph[from].push(to);
+            graph[to].push(from);
+        }
+    }
+
+    Ok(graph)
+}
+
+// The product of both group sizes is the answer
+#[derive(Debug)]
+pub struct Cut {
+    pub product: usize,
+    pub sizes: (usize, usize),
+}
+
+impl std::fmt::Display for Cut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.product)
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Graph;
+    type Answer1 = Cut;
+    type Answer2 = String;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (cut, side) = min_cut(input).ok_or("Need at least two components")?;
+        if cut != 3 {
+            return Err(format!("Expected to cut 3 wires, the minimum cut is {cut}").into());
+        }
+
+        let left = side.iter().filter(|x| **x).count();
+        let right = side.len() - left;
+
+        Ok(Cut {
+            product: left * right,
+            sizes: (left, right),
+        })
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Res<Self::Answer2> {
+        Err("Day 25 has no part 2".into())
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    grid::{Grid, Point},
+    input::Res,
+    solution::Solution,
+};
+
+fn is_symbol(c: &char) -> bool {
+    !c.is_ascii_digit() && c != &'.'
+}
+
+// Every number of the schematic with the cells its digits take
+fn numbers(grid: &Grid<char>) -> Vec<(u32, Vec<Point>)> {
+    let mut numbers = vec![];
+
+    for (i, row) in grid.iter_rows().enumerate() {
+        let mut j = 0;
+        while j < row.len() {
+            let len = row[j..].iter().take_while(|c| c.is_ascii_digit()).count();
+            if len == 0 {
+                j += 1;
+                continue;
+            }
+
+            let number = row[j..j + len]
+                .iter()
+                .fold(0, |number, c| number * 10 + c.to_digit(10).unwrap_or(0));
+            let cells = (j..j + len).map(|j| (i as i32, j as i32)).collect();
+            numbers.push((number, cells));
+
+            j += len;
+        }
+    }
+
+    numbers
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        Ok(Grid::parse(3, text, Some)?)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let grid = input;
+
+        Ok(numbers(grid)
+            .into_iter()
+            .filter(|(_, cells)| {
+                cells
+                    .iter()
+                    .flat_map(|cell| grid.neighbors8(*cell))
+                    .any(|to| is_symbol(&grid[to]))
+            })
+            .map(|(number, _)| number)
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let grid = input;
+        let mut symbol_numbers: HashMap<Point, HashSet<usize>> = HashMap::new();
+
+        let numbers = numbers(grid);
+        for (index, (_, cells)) in numbers.iter().enumerate() {
+            for to in cells.iter().flat_map(|cell| grid.neighbors8(*cell)) {
+                if is_symbol(&grid[to]) {
+                    symbol_numbers.entry(to).or_default().insert(index);
+                }
+            }
+        }
+
+        Ok(symbol_numbers
+            .into_values()
+            .filter(|value| value.len() == 2)
+            .map(|value| {
+                value
+                    .into_iter()
+                    .map(|index| numbers[index].0)
+                    .product::<u32>()
+            })
+            .sum())
+    }
+}
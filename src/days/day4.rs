@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    solution::Solution,
+};
+
+// number -> count
+type Numbers = HashMap<u32, u32>;
+
+fn parse(text: &str) -> Res<Vec<(Numbers, Numbers)>> {
+    fn parse_numbers(line: &Line, str: &str) -> Result<Numbers, ParseError> {
+        str.split_whitespace()
+            .try_fold(Numbers::new(), |mut hm, number_str| {
+                let number = line.parse::<u32>(number_str, "number")?;
+                *hm.entry(number).or_insert(0) += 1;
+                Ok(hm)
+            })
+    }
+
+    let mut result = vec![];
+
+    for line in numbered(4, text) {
+        let (_, numbers) = line.split_once(":")?;
+        let (winning_str, ours_str) = numbers.split_once('|').ok_or_else(|| line.missing("`|`"))?;
+
+        let winning = parse_numbers(&line, winning_str)?;
+        let ours = parse_numbers(&line, ours_str)?;
+
+        result.push((winning, ours));
+    }
+
+    Ok(result)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Numbers, Numbers)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        Ok(input
+            .iter()
+            .map(|(winning, ours)| {
+                ours.iter()
+                    .filter(|(key, _)| winning.contains_key(key))
+                    .fold(
+                        0,
+                        |result, (_, count)| {
+                            if result > 0 {
+                                result << count
+                            } else {
+                                1
+                            }
+                        },
+                    )
+            })
+            .sum())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let cards = input;
+        let mut cards_type_cnt = vec![1; cards.len()];
+
+        Ok(cards
+            .iter()
+            .enumerate()
+            .map(|(ind, (winning, ours))| {
+                let wins = ours
+                    .iter()
+                    .filter_map(|(number, count)| {
+                        if winning.contains_key(number) {
+                            Some(count)
+                        } else {
+                            None
+                        }
+                    })
+                    .sum::<u32>();
+
+                for next_ind in (ind + 1)..((ind + 1 + wins as usize).min(cards_type_cnt.len())) {
+                    cards_type_cnt[next_ind] += cards_type_cnt[ind];
+                }
+
+                cards_type_cnt[ind]
+            })
+            .sum())
+    }
+}
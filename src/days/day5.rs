@@ -0,0 +1,189 @@
+use std::collections::VecDeque;
+
+use crate::{
+    input::{numbered, ParseError, Res},
+    solution::Solution,
+};
+
+type Seeds = Vec<i64>;
+type MappingRange = (i64, i64, i64);
+type Mapping = (String, String, Vec<MappingRange>);
+
+fn parse_input(text: &str) -> Res<(Seeds, Vec<Mapping>)> {
+    let lines = numbered(5, text).collect::<Vec<_>>();
+    let lines = lines.split(|line| line.text.is_empty()).collect::<Vec<_>>();
+
+    let mut lines_iter = lines.into_iter();
+
+    let seeds_line = lines_iter
+        .next()
+        .and_then(|block| block.first())
+        .ok_or_else(|| ParseError::missing(5, text, "seeds"))?;
+    let seeds = seeds_line
+        .text
+        .strip_prefix("seeds:")
+        .ok_or_else(|| seeds_line.error(seeds_line.text, "expected `seeds:`"))?
+        .split_whitespace()
+        .map(|seed| seeds_line.parse::<i64>(seed, "seed"))
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    let mappings = lines_iter
+        .map(|block| {
+            let mut line_iter = block.iter();
+
+            let header = line_iter
+                .next()
+                .ok_or_else(|| ParseError::missing(5, text, "mapping"))?;
+            let from_to = header
+                .text
+                .strip_suffix(" map:")
+                .ok_or_else(|| header.error(header.text, "expected `<from>-to-<to> map:`"))?;
+            let (from, to) = from_to
+                .split_once("-to-")
+                .ok_or_else(|| header.error(from_to, "expected `<from>-to-<to>`"))?;
+
+            let mappings = line_iter
+                .map(|line| {
+                    let mapping_range = line
+                        .text
+                        .split_whitespace()
+                        .map(|str| line.parse::<i64>(str, "mapping value"))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    match mapping_range[..] {
+                        [to, from, range] => Ok((from, to, range)),
+                        _ => Err(line.error(line.text, "expected three mapping values")),
+                    }
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+
+            Ok((from.to_string(), to.to_string(), mappings))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((seeds, mappings))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Seeds, Vec<Mapping>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (seeds, mappings) = input.clone();
+
+        Ok(seeds
+            .into_iter()
+            .map(|mut seed| {
+                for (_, _, mapping) in mappings.iter() {
+                    for (from_mapping, to_mapping, range) in mapping {
+                        if *from_mapping <= seed && *from_mapping + *range > seed {
+                            let diff = seed - *from_mapping;
+                            let to = *to_mapping + diff;
+                            seed = to;
+                            break;
+                        }
+                    }
+                }
+                seed
+            })
+            .min()
+            .ok_or("There are no seeds")?)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (seeds, mappings) = input.clone();
+
+        let mut ranges_queue = VecDeque::<(i64, i64, usize)>::new();
+
+        let mut seeds_iter = seeds.into_iter();
+        while let (Some(seed_id), Some(seed_range)) = (seeds_iter.next(), seeds_iter.next()) {
+            ranges_queue.push_back((seed_id, seed_id + seed_range - 1, 0));
+        }
+
+        let mappings_depth = mappings
+            .into_iter()
+            .fold(vec![], |mut depths, (_, _, cur)| {
+                depths.push(cur);
+                depths
+            });
+
+        let mut ans = i64::MAX;
+        while let Some((seed_left, seed_right, depth)) = ranges_queue.pop_front() {
+            let left_point_seed = seed_left;
+            let right_point_seed = seed_right;
+
+            if depth >= mappings_depth.len() {
+                ans = ans.min(left_point_seed);
+            } else {
+                let mut intersections = vec![];
+                for (from_mapping, to_mapping, range) in mappings_depth[depth].iter() {
+                    let left_point_mapping = *from_mapping;
+                    let right_point_mapping = *from_mapping + *range - 1;
+
+                    // Find inteesection between 2 ranges
+                    let left_point = left_point_seed.max(left_point_mapping);
+                    let right_point = right_point_seed.min(right_point_mapping);
+
+                    if left_point <= right_point {
+                        let diff = *to_mapping - *from_mapping;
+
+                        let left_point_mapped = left_point + diff;
+                        let right_point_mapped = right_point + diff;
+
+                        ranges_queue.push_back((left_point_mapped, right_point_mapped, depth + 1));
+                        intersections.push((left_point, right_point));
+                    }
+                }
+
+                intersections.sort_unstable();
+
+                if !intersections.is_empty() {
+                    // Add left tail
+                    let most_left_intersection = intersections.first().unwrap().0;
+                    if left_point_seed < most_left_intersection {
+                        ranges_queue.push_back((
+                            left_point_seed,
+                            most_left_intersection,
+                            depth + 1,
+                        ));
+                    }
+
+                    // Add right tail
+                    let most_right_intersection = intersections.last().unwrap().1;
+                    if right_point_seed > most_right_intersection {
+                        ranges_queue.push_back((
+                            most_right_intersection + 1,
+                            right_point_seed,
+                            depth + 1,
+                        ));
+                    }
+
+                    // Add range beetwen intersections
+                    for intersection in intersections.windows(2) {
+                        let left_intersection = intersection[0];
+                        let right_intersection = intersection[1];
+                        if right_intersection.0 - left_intersection.1 > 1 {
+                            ranges_queue.push_back((
+                                right_intersection.1 + 1,
+                                left_intersection.0 - 1,
+                                depth + 1,
+                            ));
+                        }
+                    }
+                } else {
+                    // Add all inittial range
+                    ranges_queue.push_back((left_point_seed, right_point_seed, depth + 1));
+                }
+            }
+        }
+
+        Ok(ans)
+    }
+}
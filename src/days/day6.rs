@@ -0,0 +1,131 @@
+use crate::{
+    input::{numbered, Line, ParseError, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<(Vec<i64>, Vec<i64>)> {
+    fn parse_row<'a>(
+        text: &str,
+        line: Option<Line<'a>>,
+        name: &str,
+    ) -> Result<(Line<'a>, Vec<i64>), ParseError> {
+        let line = line.ok_or_else(|| ParseError::missing(6, text, &format!("`{name}` row")))?;
+
+        let values = line
+            .text
+            .strip_prefix(name)
+            .and_then(|values| values.strip_prefix(':'))
+            .ok_or_else(|| line.error(line.text, &format!("expected `{name}:`")))?;
+
+        let values = values
+            .split_whitespace()
+            .map(|x| line.parse::<i64>(x, "number"))
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(line.missing("numbers"));
+        }
+
+        Ok((line, values))
+    }
+
+    let mut lines_iter = numbered(6, text);
+
+    let (_, time) = parse_row(text, lines_iter.next(), "Time")?;
+    let (line, distance) = parse_row(text, lines_iter.next(), "Distance")?;
+
+    // One distance per race
+    if distance.len() != time.len() {
+        return Err(line
+            .error(
+                line.text,
+                &format!("expected {} distances, one per race", time.len()),
+            )
+            .into());
+    }
+
+    Ok((time, distance))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Vec<i64>, Vec<i64>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (time, distance) = input;
+
+        Ok(time
+            .iter()
+            .zip(distance)
+            .map(|(&time, &distance)| {
+                (0..=time).filter(|t| (time - t) * t > distance).count() as i64
+            })
+            .product::<i64>())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (time, distance) = input;
+
+        let time = time
+            .iter()
+            .fold(String::new(), |mut str, s| {
+                str.push_str(&s.to_string());
+                str
+            })
+            .parse::<i64>()?;
+
+        let distance = distance
+            .iter()
+            .fold(String::new(), |mut str, s| {
+                str.push_str(&s.to_string());
+                str
+            })
+            .parse::<i64>()?;
+
+        /*
+            x * (time - x) >= distance
+            -x*x + time*x - distance >= 0
+            x*x - time*x + distance <= 0
+        */
+
+        let sqrt = ((time * time - 4 * distance) as f64).sqrt();
+
+        let left = (time as f64 - sqrt) / 2.;
+        let right = (time as f64 + sqrt) / 2.;
+
+        let left = left.ceil();
+        let right = right.floor();
+
+        let ans = (right - left) as i64 + 1;
+
+        Ok(ans)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_input;
+    use crate::input::ParseError;
+
+    #[test]
+    fn rows_are_checked() {
+        for (text, line, reason) in [
+            ("Time:\nDistance:", 1, "missing numbers"),
+            (
+                "Time: 7 15\nDistance: 9",
+                2,
+                "expected 2 distances, one per race",
+            ),
+        ] {
+            let err = parse_input(text).unwrap_err();
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            assert_eq!((err.line, err.reason.as_str()), (line, reason));
+        }
+    }
+}
@@ -1,46 +1,41 @@
 use std::collections::HashMap;
 
-use input::{read_lines, Res};
+use crate::{
+    input::{numbered, ParseError, Res},
+    solution::Solution,
+};
 
-mod input;
-
-const IN_SMALL: &str = "assets/day7/in_small.txt";
-const IN: &str = "assets/day7/in.txt";
+type Cards = HashMap<char, u32>;
 
-fn main() -> Res<()> {
-    let ans_part_1 = part1(IN)?;
-    let ans_part_2 = part2(IN)?;
+fn parse_input(text: &str) -> Res<Vec<(String, u32)>> {
+    let result = numbered(7, text)
+        .map(|line| {
+            let mut line_iter = line.text.split_whitespace();
+            let hand = line_iter.next().ok_or_else(|| line.missing("hand"))?;
+            let bid = line_iter.next().ok_or_else(|| line.missing("bid"))?;
 
-    println!("Part 1: {ans_part_1}");
-    println!("Part 2: {ans_part_2}");
+            line.check_chars(hand, &KINDS_NO_J.iter().collect::<String>(), "card")?;
+            let bid = line.parse::<u32>(bid, "bid")?;
 
-    assert!(ans_part_1 == 248453531);
-    assert!(ans_part_2 == 248781813);
+            Ok((hand.to_string(), bid))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
 
-    Ok(())
+    Ok(result)
 }
 
-type Cards = HashMap<char, u32>;
-
-fn parse_input(lines: &Vec<String>) -> Res<Vec<(Cards, String, u32)>> {
-    let result = lines
+fn count_cards(hands: &[(String, u32)]) -> Vec<(Cards, String, u32)> {
+    hands
         .iter()
-        .map(|line| {
-            let mut line_iter = line.split_whitespace().into_iter();
-            let hand = line_iter.next().unwrap();
-            let bid = line_iter.next().unwrap();
-
+        .map(|(hand, bid)| {
             let hand_cards = hand.chars().fold(HashMap::new(), |mut hm, card| {
                 *hm.entry(card).or_insert(0) += 1;
                 hm
             });
-            let bid = bid.parse::<u32>().ok().expect("Could not parse a bid");
 
-            (hand_cards, hand.to_string(), bid)
+            (hand_cards, hand.clone(), *bid)
         })
-        .collect::<Vec<_>>();
-
-    Ok(result)
+        .collect::<Vec<_>>()
 }
 
 const KINDS: &[char] = &[
@@ -133,8 +128,8 @@ fn solve(mut data: Vec<(Cards, String, u32)>) -> Res<u32> {
     ];
 
     data.sort_by(|(cards_a, hand_a, _), (cards_b, hand_b, _)| {
-        let a_ = arr.iter().position(|f| (f)(&cards_a)).unwrap_or(10);
-        let b_ = arr.iter().position(|f| (f)(&cards_b)).unwrap_or(10);
+        let a_ = arr.iter().position(|f| (f)(cards_a)).unwrap_or(10);
+        let b_ = arr.iter().position(|f| (f)(cards_b)).unwrap_or(10);
 
         match b_.cmp(&a_) {
             std::cmp::Ordering::Equal => hand_a
@@ -147,8 +142,8 @@ fn solve(mut data: Vec<(Cards, String, u32)>) -> Res<u32> {
 
                     kind_b.cmp(&kind_a)
                 })
-                .unwrap(),
-            n @ _ => n,
+                .unwrap_or(std::cmp::Ordering::Equal),
+            n => n,
         }
     });
 
@@ -159,20 +154,27 @@ fn solve(mut data: Vec<(Cards, String, u32)>) -> Res<u32> {
         .sum::<u32>())
 }
 
-// 248453531
-fn part1(file: &str) -> Res<u32> {
-    let lines = read_lines(file)?;
+pub struct Day7;
 
-    Ok(solve(parse_input(&lines)?)?)
-}
+impl Solution for Day7 {
+    type Input = Vec<(String, u32)>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-// 248781813
-fn part2(file: &str) -> Res<u32> {
-    let lines = read_lines(file)?;
-    let lines = lines
-        .into_iter()
-        .map(|line| line.replace("J", "."))
-        .collect();
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        solve(count_cards(input))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let hands = input
+            .iter()
+            .map(|(hand, bid)| (hand.replace('J', "."), *bid))
+            .collect::<Vec<_>>();
 
-    Ok(solve(parse_input(&lines)?)?)
+        solve(count_cards(&hands))
+    }
 }
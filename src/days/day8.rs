@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::{
+    input::{numbered, ParseError, Res},
+    math::lcm,
+    solution::Solution,
+};
+
+type Rules = HashMap<String, [String; 2]>;
+
+fn parse_input(text: &str) -> Res<(Vec<usize>, Rules)> {
+    let mut lines_iter = numbered(8, text).filter(|line| !line.text.is_empty());
+
+    let line = lines_iter
+        .next()
+        .ok_or_else(|| ParseError::missing(8, text, "instructions"))?;
+    let instructions = line
+        .text
+        .char_indices()
+        .map(|(offset, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(line.error_at(offset, &c.to_string(), "unknown instruction")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rules = lines_iter
+        .map(|line| {
+            let (from, to) = line.split_once("=")?;
+            let to = to.trim_matches([' ', '(', ')'].as_slice());
+            let (left, right) = to
+                .split_once(", ")
+                .ok_or_else(|| line.error(to, "expected `(<left>, <right>)`"))?;
+
+            Ok((
+                from.trim().to_string(),
+                [left.to_string(), right.to_string()],
+            ))
+        })
+        .try_fold(HashMap::new(), |mut hm, rule: Result<_, ParseError>| {
+            let (from, to) = rule?;
+            hm.insert(from, to);
+            Ok::<_, ParseError>(hm)
+        })?;
+
+    Ok((instructions, rules))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Vec<usize>, Rules);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let (instructions, rules) = input;
+
+        let instructions_len = instructions.len();
+        let mut instruction_ind = 0;
+        let mut cnt = 0;
+        let mut cur = "AAA";
+
+        while cur != "ZZZ" {
+            let next = rules
+                .get(cur)
+                .ok_or_else(|| format!("There is no node `{cur}`"))?;
+            cur = &next[instructions[instruction_ind]];
+            instruction_ind = (instruction_ind + 1) % instructions_len;
+            cnt += 1;
+        }
+
+        Ok(cnt)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let (instructions, rules) = input;
+
+        let instructions_len = instructions.len();
+        let starts = rules
+            .keys()
+            .filter(|key| key.ends_with("A"))
+            .collect::<Vec<_>>();
+
+        let cnt = starts
+            .into_iter()
+            .map(|mut cur| {
+                let mut instruction_ind = 0;
+                let mut cnt = 0;
+
+                while !cur.ends_with("Z") {
+                    let next = rules
+                        .get(cur)
+                        .ok_or_else(|| format!("There is no node `{cur}`"))?;
+                    cur = &next[instructions[instruction_ind]];
+                    instruction_ind = (instruction_ind + 1) % instructions_len;
+                    cnt += 1;
+                }
+
+                Ok(cnt)
+            })
+            .collect::<Res<Vec<u64>>>()?
+            .into_iter()
+            .fold(1, lcm);
+
+        Ok(cnt)
+    }
+}
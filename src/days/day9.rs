@@ -0,0 +1,81 @@
+use crate::{
+    input::{numbered, Res},
+    solution::Solution,
+};
+
+fn parse_input(text: &str) -> Res<Vec<Vec<i64>>> {
+    let lines = numbered(9, text)
+        .map(|line| {
+            line.text
+                .split_whitespace()
+                .map(|x| line.parse::<i64>(x, "value"))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(lines)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, text: &str) -> Res<Self::Input> {
+        parse_input(text)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1> {
+        let lines = input.clone();
+
+        Ok(lines.into_iter().fold(0, |ans, mut line| {
+            let mut tail = vec![];
+
+            while !line.iter().all(|x| x == &0) {
+                tail.push(
+                    *line
+                        .last()
+                        .expect("Could not get a value. Is the list empty?"),
+                );
+                line = line.windows(2).map(|x| x[1] - x[0]).collect();
+            }
+
+            tail.reverse();
+            let right_most: Vec<i64> = tail.into_iter().fold(vec![], |mut v, x| {
+                let next = v.last().unwrap_or(&0) + x;
+                v.push(next);
+                v
+            });
+
+            ans + right_most.last().unwrap_or(&0)
+        }))
+    }
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2> {
+        let lines = input.clone();
+
+        Ok(lines.into_iter().fold(0, |ans, mut line| {
+            let mut head = vec![];
+
+            while !line.iter().all(|x| x == &0) {
+                head.push(
+                    *line
+                        .first()
+                        .expect("Could not get a value. Is the list empty?"),
+                );
+
+                line = line.windows(2).map(|x| x[1] - x[0]).collect();
+            }
+
+            head.reverse();
+            let left_most = head.into_iter().fold(vec![], |mut v, x| {
+                let next = x - v.last().unwrap_or(&0);
+                v.push(next);
+                v
+            });
+
+            ans + left_most.last().unwrap_or(&0)
+        }))
+    }
+}
@@ -0,0 +1,60 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::solution::DynSolution;
+
+// `DAYS[n - 1]` is the n-th day of the calendar
+pub const DAYS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10 { draw: false },
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16 {
+        draw: false,
+        trace: false,
+        threads: None,
+    },
+    &day17::Day17::PUZZLE,
+    &day18::Day18 { svg: None },
+    &day19::Day19,
+    &day20::Day20::PUZZLE,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23 { dot: None },
+    &day24::Day24,
+    &day25::Day25,
+];
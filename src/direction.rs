@@ -0,0 +1,95 @@
+use crate::grid::Point;
+
+// The four ways to move on a grid, rows grow downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise from up, `index` is the position in this list
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    // Row and column change of a single step
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+
+    pub fn step(self, point: Point) -> Point {
+        self.step_by(point, 1)
+    }
+
+    pub fn step_by(self, (i, j): Point, steps: i32) -> Point {
+        let (di, dj) = self.offset();
+
+        (i + steps * di, j + steps * dj)
+    }
+
+    // `U`, `R`, `D` or `L`
+    pub fn from_letter(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.letter() == c)
+    }
+
+    pub fn letter(self) -> char {
+        ['U', 'R', 'D', 'L'][self.index()]
+    }
+
+    // `^`, `>`, `v` or `<`
+    pub fn from_arrow(c: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.arrow() == c)
+    }
+
+    pub fn arrow(self) -> char {
+        ['^', '>', 'v', '<'][self.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+
+    #[test]
+    fn direction_works() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(Direction::from_letter(dir.letter()), Some(dir));
+            assert_eq!(Direction::from_arrow(dir.arrow()), Some(dir));
+            assert_eq!(dir.reverse().step(dir.step((3, 4))), (3, 4));
+        }
+
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.step_by((3, 4), 2), (3, 2));
+        assert_eq!(Direction::from_letter('X'), None);
+    }
+}
@@ -0,0 +1,143 @@
+// Polygons on the integer lattice, given by their vertices in order. The last vertex connects back
+// to the first one, collinear vertices in between are fine.
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::math::gcd;
+
+pub trait Coord:
+    Copy
+    + Default
+    + Ord
+    + From<i32>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl Coord for i64 {}
+impl Coord for i128 {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn abs<T: Coord>(x: T) -> T {
+    if x < T::default() {
+        -x
+    } else {
+        x
+    }
+}
+
+fn edges<T: Coord>(vertices: &[(T, T)]) -> impl Iterator<Item = ((T, T), (T, T))> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| (*a, *b))
+}
+
+// Twice the area, so that it stays an integer. Positive when the vertices go counterclockwise
+// with `y` pointing up, the sign flips with either of them.
+pub fn double_signed_area<T: Coord>(vertices: &[(T, T)]) -> T {
+    // Shoelace formula
+    edges(vertices).fold(T::default(), |area, ((x1, y1), (x2, y2))| {
+        area + x1 * y2 - x2 * y1
+    })
+}
+
+// Length of the boundary when walking along the grid, which is the true length
+// when every edge is horizontal or vertical
+pub fn perimeter<T: Coord>(vertices: &[(T, T)]) -> T {
+    edges(vertices).fold(T::default(), |p, ((x1, y1), (x2, y2))| {
+        p + abs(x2 - x1) + abs(y2 - y1)
+    })
+}
+
+// Lattice points on the edges
+pub fn boundary_points<T: Coord>(vertices: &[(T, T)]) -> T {
+    edges(vertices).fold(T::default(), |b, ((x1, y1), (x2, y2))| {
+        b + abs(gcd(x2 - x1, y2 - y1))
+    })
+}
+
+// Lattice points strictly inside, from Pick's theorem `A = I + B / 2 - 1`
+pub fn interior_points<T: Coord>(vertices: &[(T, T)]) -> T {
+    let two = T::from(2);
+
+    (abs(double_signed_area(vertices)) - boundary_points(vertices) + two) / two
+}
+
+pub fn locate<T: Coord>(vertices: &[(T, T)], (x, y): (T, T)) -> Location {
+    let mut inside = false;
+
+    for ((x1, y1), (x2, y2)) in edges(vertices) {
+        let on_line = (x2 - x1) * (y - y1) == (y2 - y1) * (x - x1);
+        if on_line && x1.min(x2) <= x && x <= x1.max(x2) && y1.min(y2) <= y && y <= y1.max(y2) {
+            return Location::Boundary;
+        }
+
+        // Cast a ray towards growing `x` and count the edges it crosses. An edge counts
+        // when it has one end strictly above the ray, so vertices on the ray are counted once.
+        if (y1 > y) != (y2 > y) {
+            let side = (x - x1) * (y2 - y1) - (y - y1) * (x2 - x1);
+            if (side < T::default()) == (y2 > y1) {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        boundary_points, double_signed_area, interior_points, locate, perimeter, Location,
+    };
+
+    #[test]
+    fn polygon_works() {
+        // An L shape: a 4x4 square without its top right 2x2 quarter
+        let shape: &[(i64, i64)] = &[(0, 0), (4, 0), (4, 2), (2, 2), (2, 4), (0, 4)];
+
+        assert_eq!(double_signed_area(shape), 24);
+        assert_eq!(
+            double_signed_area(&shape.iter().rev().copied().collect::<Vec<_>>()),
+            -24
+        );
+        assert_eq!(perimeter(shape), 16);
+        assert_eq!(boundary_points(shape), 16);
+        assert_eq!(interior_points(shape), 5);
+
+        assert_eq!(locate(shape, (1, 1)), Location::Inside);
+        assert_eq!(locate(shape, (1, 3)), Location::Inside);
+        assert_eq!(locate(shape, (3, 2)), Location::Boundary);
+        assert_eq!(locate(shape, (0, 0)), Location::Boundary);
+        assert_eq!(locate(shape, (3, 3)), Location::Outside);
+        assert_eq!(locate(shape, (-1, 2)), Location::Outside);
+        assert_eq!(locate(shape, (5, 0)), Location::Outside);
+    }
+
+    #[test]
+    fn triangle_works() {
+        let triangle: &[(i128, i128)] = &[(0, 0), (6, 0), (0, 3)];
+
+        assert_eq!(double_signed_area(triangle), 18);
+        assert_eq!(boundary_points(triangle), 12);
+        assert_eq!(interior_points(triangle), 4);
+        assert_eq!(locate(triangle, (2, 2)), Location::Boundary);
+        assert_eq!(locate(triangle, (1, 1)), Location::Inside);
+        assert_eq!(locate(triangle, (3, 2)), Location::Outside);
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Distances from `start` to everything reachable in at most `max_depth` steps
+pub fn bfs<N, F, I>(start: N, max_depth: usize, mut successors: F) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut dist = HashMap::<N, usize>::new();
+    let mut q = VecDeque::<N>::new();
+
+    dist.insert(start.clone(), 0);
+    q.push_back(start);
+
+    while let Some(cur) = q.pop_front() {
+        let d = dist[&cur];
+        if d == max_depth {
+            continue;
+        }
+
+        for next in successors(&cur) {
+            if !dist.contains_key(&next) {
+                dist.insert(next.clone(), d + 1);
+                q.push_back(next);
+            }
+        }
+    }
+
+    dist
+}
+
+// Cheapest way from `start` to the first state accepted by `is_goal`, `successors` gives the next
+// states with the cost of moving there. Returns the cost and every state along the way, `start` first.
+pub fn dijkstra<N, C, F, I, G>(start: N, mut successors: F, mut is_goal: G) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    // States are numbered in the order they are seen, so the heap does not need `N: Ord`
+    let mut states = vec![start.clone()];
+    let mut index = HashMap::<N, usize>::from([(start, 0)]);
+    // Best known cost and the state it came from
+    let mut best: Vec<(C, Option<usize>)> = vec![(C::default(), None)];
+    let mut done = vec![false];
+    let mut q = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, cur))) = q.pop() {
+        if done[cur] {
+            continue;
+        }
+        done[cur] = true;
+
+        if is_goal(&states[cur]) {
+            let mut path = vec![];
+            let mut at = Some(cur);
+            while let Some(i) = at {
+                path.push(states[i].clone());
+                at = best[i].1;
+            }
+            path.reverse();
+
+            return Some((cost, path));
+        }
+
+        for (next, step) in successors(&states[cur]) {
+            let next_cost = cost + step;
+            let i = *index.entry(next).or_insert_with_key(|next| {
+                states.push(next.clone());
+                best.push((next_cost, None));
+                done.push(false);
+                states.len() - 1
+            });
+
+            if !done[i] && (best[i].1.is_none() || next_cost < best[i].0) {
+                best[i] = (next_cost, Some(cur));
+                q.push(Reverse((next_cost, i)));
+            }
+        }
+    }
+
+    None
+}
+
+// Unit capacity network built from the undirected graph: arc `2k` and `2k + 1` are the two directions
+// of the k-th wire, so the reverse of arc `e` is always `e ^ 1`
+struct Network {
+    heads: Vec<usize>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+impl Network {
+    fn new(graph: &[Vec<usize>]) -> Self {
+        let mut heads = vec![];
+        let mut adjacent = vec![vec![]; graph.len()];
+
+        for (from, tos) in graph.iter().enumerate() {
+            for &to in tos.iter().filter(|&&to| from < to) {
+                adjacent[from].push(heads.len());
+                heads.push(to);
+                adjacent[to].push(heads.len());
+                heads.push(from);
+            }
+        }
+
+        Network { heads, adjacent }
+    }
+
+    // Max flow from `s` to `t` stopping as soon as it reaches `limit`.
+    // Returns the flow and which vertices are still reachable from `s` in the residual network.
+    fn max_flow(&self, s: usize, t: usize, limit: usize) -> (usize, Vec<bool>) {
+        let mut flow = vec![0_i32; self.heads.len()];
+        let mut total = 0;
+
+        loop {
+            let mut from_arc = vec![None; self.adjacent.len()];
+            let mut reachable = vec![false; self.adjacent.len()];
+            let mut q = VecDeque::new();
+
+            reachable[s] = true;
+            q.push_back(s);
+            while let Some(cur) = q.pop_front() {
+                for &arc in &self.adjacent[cur] {
+                    let to = self.heads[arc];
+                    if !reachable[to] && flow[arc] < 1 {
+                        reachable[to] = true;
+                        from_arc[to] = Some(arc);
+                        q.push_back(to);
+                    }
+                }
+            }
+
+            if !reachable[t] || total == limit {
+                return (total, reachable);
+            }
+
+            let mut cur = t;
+            while let Some(arc) = from_arc[cur] {
+                flow[arc] += 1;
+                flow[arc ^ 1] -= 1;
+                cur = self.heads[arc ^ 1];
+            }
+            total += 1;
+        }
+    }
+}
+
+// Global minimum edge cut: every cut separates vertex 0 from some other vertex,
+// so it is the smallest of the `0 -> t` max flows.
+// Returns the size of the cut and the side vertex 0 ends up on.
+pub fn min_cut(graph: &[Vec<usize>]) -> Option<(usize, Vec<bool>)> {
+    let network = Network::new(graph);
+
+    let mut best: Option<(usize, Vec<bool>)> = None;
+    for t in 1..graph.len() {
+        let limit = best.as_ref().map_or(usize::MAX, |(cut, _)| *cut);
+        let (cut, side) = network.max_flow(0, t, limit);
+
+        if cut < limit {
+            best = Some((cut, side));
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bfs, dijkstra, min_cut};
+
+    #[test]
+    fn bfs_works() {
+        let dist = bfs(0, 3, |x: &i32| [x - 1, x + 1]);
+
+        assert_eq!(dist.len(), 7);
+        assert_eq!(dist[&-3], 3);
+        assert_eq!(dist[&2], 2);
+    }
+
+    #[test]
+    fn dijkstra_works() {
+        // Stepping by one costs 1, jumping to the double costs 3
+        let (cost, path) = dijkstra(1, |&x: &i32| [(x + 1, 1), (x * 2, 3)], |&x| x == 10).unwrap();
+
+        assert_eq!(cost, 7);
+        assert_eq!(path.first(), Some(&1));
+        assert_eq!(path.last(), Some(&10));
+        assert_eq!(dijkstra(0, |&x: &i32| [(x, 1)], |&x| x == 1), None);
+    }
+
+    #[test]
+    fn min_cut_works() {
+        // Two triangles joined by a single edge
+        let graph = vec![
+            vec![1, 2],
+            vec![0, 2],
+            vec![0, 1, 3],
+            vec![2, 4, 5],
+            vec![3, 5],
+            vec![3, 4],
+        ];
+
+        let (cut, side) = min_cut(&graph).unwrap();
+
+        assert_eq!(cut, 1);
+        assert_eq!(side, vec![true, true, true, false, false, false]);
+    }
+}
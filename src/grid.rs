@@ -0,0 +1,272 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    direction::Direction,
+    input::{parse_cells, parse_rows, Line, ParseError},
+};
+
+// Row and column, signed so that stepping off the grid is just a failed lookup
+pub type Point = (i32, i32);
+
+const NEIGHBORS8: &[Point; 8] = &[
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+// A rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    // `None` when the rows have different widths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Every character becomes a cell, `to_cell` returns `None` for unknown ones
+    pub fn parse(
+        day: usize,
+        text: &str,
+        to_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Ok(Self::from_parsed(parse_cells(day, text, to_cell)?))
+    }
+
+    // Same as `parse` for a block of lines, e.g. one of several grids in the input
+    pub fn parse_rows(
+        lines: &[Line],
+        to_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        Ok(Self::from_parsed(parse_rows(lines, to_cell)?))
+    }
+
+    // The parsers already checked that every row has the same width
+    fn from_parsed(rows: Vec<Vec<T>>) -> Self {
+        Grid {
+            rows: rows.len(),
+            cols: rows.first().map_or(0, |row| row.len()),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn offset(&self, (i, j): Point) -> Option<usize> {
+        let i = usize::try_from(i).ok().filter(|i| *i < self.rows)?;
+        let j = usize::try_from(j).ok().filter(|j| *j < self.cols)?;
+
+        Some(i * self.cols + j)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(j)
+            .step_by(self.cols.max(1))
+            .take(self.rows)
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn iter_cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(|j| self.column(j))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let cols = self.cols;
+
+        (0..self.rows).flat_map(move |i| (0..cols).map(move |j| (i as i32, j as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find_map(|(point, cell)| predicate(cell).then_some(point))
+    }
+
+    // Up, right, down and left, skipping the ones off the grid
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| dir.step(point))
+            .filter(|to| self.contains(*to))
+    }
+
+    // Clockwise from up, diagonals included, skipping the ones off the grid
+    pub fn neighbors8(&self, (i, j): Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS8
+            .iter()
+            .map(move |(di, dj)| (i + di, j + dj))
+            .filter(|to| self.contains(*to))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.iter_cols().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    // A quarter turn clockwise: the first column read bottom up becomes the first row
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self
+                .iter_cols()
+                .flat_map(|col| col.rev())
+                .cloned()
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+// Out of the grid is a bug in the caller, just like for `Vec`
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.offset(point) {
+            Some(offset) => &self.cells[offset],
+            None => panic!("{point:?} is out of a {}x{} grid", self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+
+        match self.offset(point) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!("{point:?} is out of a {rows}x{cols} grid"),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+
+    #[test]
+    fn grid_works() {
+        let grid = Grid::parse(0, "#..\n.#.", Some).unwrap();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.get((1, 1)), Some(&'#'));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.row(1), &['.', '#', '.']);
+        assert_eq!(grid.column(2).collect::<String>(), "..");
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.rotate().to_string(), ".#\n#.\n..");
+        assert_eq!(grid.transpose().to_string(), "#.\n.#\n..");
+        assert_eq!(grid.to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn transpose_works() {
+        let v1 = vec![vec![1, 2], vec![3, 4], vec![5, 6], vec![7, 8]];
+        let v2 = vec![vec![1, 3, 5, 7], vec![2, 4, 6, 8]];
+
+        let v1 = Grid::from_rows(v1).unwrap().transpose();
+
+        assert!(v1 == Grid::from_rows(v2).unwrap())
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::read_to_string,
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+pub type Res<T> = core::result::Result<T, Box<dyn Error>>;
+
+// `-` reads the puzzle from stdin, anything else is a path
+pub fn read_input(path: &str) -> Res<String> {
+    if path == "-" {
+        read_from(io::stdin().lock())
+    } else {
+        Ok(read_to_string(path)?)
+    }
+}
+
+pub fn read_from<R: BufRead>(mut reader: R) -> Res<String> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    Ok(text)
+}
+
+pub fn to_lines(text: &str) -> Vec<String> {
+    text.lines().map(|line| line.to_owned()).collect::<Vec<_>>()
+}
+
+// Path to one of the checked in inputs of the day, e.g. `asset(17, "in_small")`
+pub fn asset(day: usize, name: &str) -> String {
+    format!("assets/day{day}/{name}.txt")
+}
+
+// Where a part writes its own copy of an output file, e.g. `lagoon.svg` becomes `lagoon-2.svg`
+pub fn part_path(path: &str, part: usize) -> String {
+    match path.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() && !extension.contains('/') => {
+            format!("{stem}-{part}.{extension}")
+        }
+        _ => format!("{path}-{part}"),
+    }
+}
+
+// A malformed puzzle input, pointing at the offending text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: usize,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    // The input stopped before `what` was found
+    pub fn missing(day: usize, text: &str, what: &str) -> Self {
+        ParseError {
+            day,
+            line: text.lines().count() + 1,
+            column: 1,
+            text: String::new(),
+            reason: format!("missing {what}"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+// One line of a day's input, numbered from 1 like an editor does
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: usize,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // `token` should be a slice of the line, so the column can be found exactly
+    pub fn error(&self, token: &str, reason: &str) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= self.text.len())
+            .or_else(|| self.text.find(token))
+            .unwrap_or(0);
+
+        self.error_at(offset, token, reason)
+    }
+
+    // `offset` is the byte offset of `token` in the line
+    pub fn error_at(&self, offset: usize, token: &str, reason: &str) -> ParseError {
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset.min(self.text.len())].chars().count() + 1,
+            text: token.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    // Points right after the end of the line
+    pub fn missing(&self, what: &str) -> ParseError {
+        self.error_at(self.text.len(), "", &format!("missing {what}"))
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &'a str, what: &str) -> Result<T, ParseError> {
+        token
+            .parse::<T>()
+            .map_err(|_| self.error(token, &format!("invalid {what}")))
+    }
+
+    // Every character of `token` must be one of `allowed`
+    pub fn check_chars(&self, token: &'a str, allowed: &str, what: &str) -> Result<(), ParseError> {
+        match token.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            Some((offset, c)) => Err(self.error(
+                &token[offset..offset + c.len_utf8()],
+                &format!("unknown {what}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.missing(&format!("`{separator}`")))
+    }
+}
+
+pub fn numbered(day: usize, text: &str) -> impl Iterator<Item = Line<'_>> {
+    text.lines().enumerate().map(move |(i, text)| Line {
+        day,
+        number: i + 1,
+        text,
+    })
+}
+
+// Parses every character of a grid, `to_cell` returns `None` for unknown ones
+pub fn parse_cells<T>(
+    day: usize,
+    text: &str,
+    to_cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let lines = numbered(day, text).collect::<Vec<_>>();
+    if lines.is_empty() {
+        return Err(ParseError::missing(day, text, "grid"));
+    }
+
+    parse_rows(&lines, to_cell)
+}
+
+// Same as `parse_cells` for a block of lines, e.g. one of several grids in the input
+pub fn parse_rows<T>(
+    lines: &[Line],
+    mut to_cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut mtx: Vec<Vec<T>> = vec![];
+
+    for line in lines {
+        let row = line
+            .text
+            .char_indices()
+            .map(|(offset, c)| {
+                to_cell(c)
+                    .ok_or_else(|| line.error_at(offset, &c.to_string(), "unexpected character"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if mtx.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(line.error(line.text, "row width differs from the first row"));
+        }
+
+        mtx.push(row);
+    }
+
+    Ok(mtx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{numbered, parse_cells, part_path, ParseError};
+
+    #[test]
+    fn part_path_works() {
+        assert_eq!(part_path("lagoon.svg", 1), "lagoon-1.svg");
+        assert_eq!(part_path("out/lagoon.v2.svg", 2), "out/lagoon.v2-2.svg");
+        assert_eq!(part_path("./lagoon", 2), "./lagoon-2");
+        assert_eq!(part_path(".hidden", 1), ".hidden-1");
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_text() {
+        let line = numbered(7, "32T3K 765\nT55J5 6x4").nth(1).unwrap();
+        let bid = line.text.split_whitespace().nth(1).unwrap();
+
+        assert_eq!(
+            line.parse::<u32>(bid, "bid"),
+            Err(ParseError {
+                day: 7,
+                line: 2,
+                column: 7,
+                text: "6x4".to_string(),
+                reason: "invalid bid".to_string(),
+            })
+        );
+
+        let err = parse_cells(10, "F-7\n|?|\nL-J", |c| (c != '?').then_some(c)).unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "?"));
+        assert_eq!(
+            err.to_string(),
+            "day 10, line 2, column 2: unexpected character `?`"
+        );
+    }
+}
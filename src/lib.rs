@@ -0,0 +1,8 @@
+pub mod days;
+pub mod direction;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod math;
+pub mod solution;
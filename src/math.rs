@@ -0,0 +1,37 @@
+use std::ops::{Div, Mul, Rem};
+
+pub fn gcd<T>(mut a: T, mut b: T) -> T
+where
+    T: Copy + Default + PartialEq + Rem<Output = T>,
+{
+    while b != T::default() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: Copy + Default + PartialEq + Rem<Output = T> + Div<Output = T> + Mul<Output = T>,
+{
+    a / gcd(a, b) * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gcd, lcm};
+
+    #[test]
+    fn gcd_works() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(18_u64, 12), 6);
+        assert_eq!(gcd(7_u64, 0), 7);
+        assert_eq!(gcd(-12_i128, 18).abs(), 6);
+    }
+
+    #[test]
+    fn lcm_works() {
+        assert_eq!(lcm(4_u64, 6), 12);
+        assert_eq!([3_u64, 4, 5].into_iter().fold(1, lcm), 60);
+    }
+}
@@ -0,0 +1,67 @@
+use std::{any::Any, fmt::Display, io::BufRead};
+
+use crate::input::{read_from, Res};
+
+// A day of the calendar: the input is parsed once and both parts work on the parsed form
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // Day 25 has a single puzzle
+    const HAS_PART2: bool = true;
+
+    fn parse(&self, text: &str) -> Res<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Res<Self::Answer1>;
+
+    fn part2(&self, input: &Self::Input) -> Res<Self::Answer2>;
+
+    fn parse_reader<R: BufRead>(&self, reader: R) -> Res<Self::Input>
+    where
+        Self: Sized,
+    {
+        self.parse(&read_from(reader)?)
+    }
+}
+
+// Object safe view of a `Solution` so days with different types can live in one table
+pub trait DynSolution: Sync {
+    fn parse(&self, text: &str) -> Res<Box<dyn Any>>;
+
+    fn part1(&self, input: &dyn Any) -> Res<String>;
+
+    fn part2(&self, input: &dyn Any) -> Res<String>;
+
+    fn has_part2(&self) -> bool;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse(&self, text: &str) -> Res<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, text)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Res<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("Input was parsed by another day")?;
+
+        Ok(Solution::part1(self, input)?.to_string())
+    }
+
+    fn part2(&self, input: &dyn Any) -> Res<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or("Input was parsed by another day")?;
+
+        Ok(Solution::part2(self, input)?.to_string())
+    }
+
+    fn has_part2(&self) -> bool {
+        S::HAS_PART2
+    }
+}
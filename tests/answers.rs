@@ -0,0 +1,188 @@
+// Checks every day against the known answers in `assets/answers.toml`
+use std::{collections::HashMap, path::Path, process::ExitCode, thread};
+
+use advent_of_code::{
+    days::DAYS,
+    input::{asset, read_input, Res},
+    solution::DynSolution,
+};
+
+const MANIFEST: &str = "assets/answers.toml";
+const INPUTS: &[&str] = &["in_small", "in"];
+
+// Some days walk the grid recursively, deeper than the default stack of a spawned thread
+const STACK_SIZE: usize = 256 << 20;
+
+// (day, input) -> part -> answer
+type Answers = HashMap<(usize, String), HashMap<usize, String>>;
+
+enum Outcome {
+    Ok,
+    Failed(String),
+    Pending(String),
+}
+
+// Only the subset of TOML the manifest needs: `[dayN.input]` tables of `partN = answer`
+fn parse_manifest(text: &str) -> Res<Answers> {
+    let mut answers = Answers::new();
+    let mut table = None;
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let error = |reason: &str| format!("{MANIFEST}:{}: {reason}", i + 1);
+
+        if let Some(header) = line.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+            let (day, input) = header
+                .split_once('.')
+                .ok_or_else(|| error("expected `[dayN.input]`"))?;
+            let day = day
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<usize>().ok())
+                .filter(|day| (1..=DAYS.len()).contains(day))
+                .ok_or_else(|| error("unknown day"))?;
+            if !INPUTS.contains(&input) {
+                return Err(error("unknown input").into());
+            }
+
+            let key = (day, input.to_string());
+            answers.entry(key.clone()).or_default();
+            table = Some(key);
+            continue;
+        }
+
+        let (part, answer) = line
+            .split_once('=')
+            .ok_or_else(|| error("expected `partN = answer`"))?;
+        let part = match part.trim() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(error("unknown part").into()),
+        };
+        let answer = answer.trim().trim_matches('"').to_string();
+
+        let table = table
+            .as_ref()
+            .ok_or_else(|| error("answer outside a table"))?;
+        answers
+            .entry(table.clone())
+            .or_default()
+            .insert(part, answer);
+    }
+
+    Ok(answers)
+}
+
+fn check(day: usize, solution: &dyn DynSolution, answers: &Answers) -> Vec<(String, Outcome)> {
+    let mut outcomes = vec![];
+
+    for &name in INPUTS {
+        let file = asset(day, name);
+        let expected = answers.get(&(day, name.to_string()));
+        let parts = if solution.has_part2() { 2 } else { 1 };
+
+        if !Path::new(&file).exists() {
+            for part in 1..=parts {
+                let label = format!("day {day} {name} part {part}");
+                outcomes.push((label, Outcome::Pending(format!("no {file}"))));
+            }
+            continue;
+        }
+
+        let input = read_input(&file).and_then(|text| solution.parse(&text));
+
+        for part in 1..=parts {
+            let label = format!("day {day} {name} part {part}");
+            let expected = expected.and_then(|answers| answers.get(&part));
+
+            let ans = match &input {
+                Ok(input) if part == 1 => solution.part1(&**input),
+                Ok(input) => solution.part2(&**input),
+                Err(err) => Err(err.to_string().into()),
+            };
+
+            let outcome = match (ans, expected) {
+                (Ok(ans), Some(expected)) if &ans == expected => Outcome::Ok,
+                (Ok(ans), Some(expected)) => {
+                    Outcome::Failed(format!("expected {expected}, got {ans}"))
+                }
+                (Err(err), Some(_)) => Outcome::Failed(err.to_string()),
+                (Ok(ans), None) => Outcome::Pending(format!("no answer yet, got {ans}")),
+                (Err(err), None) => Outcome::Pending(format!("no answer yet, {err}")),
+            };
+
+            outcomes.push((label, outcome));
+        }
+    }
+
+    outcomes
+}
+
+fn main() -> ExitCode {
+    let answers = match read_input(MANIFEST).and_then(|text| parse_manifest(&text)) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Days are independent, so the slow ones run next to each other
+    let answers = &answers;
+    let outcomes = thread::scope(|scope| {
+        let handles = DAYS
+            .iter()
+            .enumerate()
+            .map(|(i, solution)| {
+                thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || check(i + 1, *solution, answers))
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, handle)| {
+                handle
+                    .map_err(drop)
+                    .and_then(|handle| handle.join().map_err(drop))
+                    .unwrap_or_else(|_| {
+                        let label = format!("day {}", i + 1);
+                        vec![(label, Outcome::Failed("panicked".to_string()))]
+                    })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    println!("\nrunning {} checks", outcomes.len());
+
+    let (mut ok, mut failed, mut pending) = (0, 0, 0);
+    for (label, outcome) in outcomes.iter() {
+        match outcome {
+            Outcome::Ok => {
+                ok += 1;
+                println!("{label} ... ok");
+            }
+            Outcome::Failed(reason) => {
+                failed += 1;
+                println!("{label} ... FAILED: {reason}");
+            }
+            Outcome::Pending(reason) => {
+                pending += 1;
+                println!("{label} ... pending: {reason}");
+            }
+        }
+    }
+
+    let result = if failed == 0 { "ok" } else { "FAILED" };
+    println!("\nanswers result: {result}. {ok} passed; {failed} failed; {pending} pending\n");
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}